@@ -0,0 +1,166 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::args::InputSource;
+use crate::day::DayId;
+
+/// Accepted answers for the real input of a day, stored in `src/answers/NN.txt`,
+/// and expected answers for its example in `src/examples/answers/NN.txt`
+/// (with a `<year>/` folder for days of a year, see [`DayId`]):
+///
+/// ```text
+/// part1: 24000
+/// part2: 45000
+/// ```
+///
+/// Multi-line answers start on the line after an empty `partN:` header.
+///
+/// Named examples `src/examples/NN-<name>.txt` have their answers in `src/examples/answers/NN-<name>.txt`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Answers {
+        let mut answers = Answers::default();
+        let mut current: Option<(u8, Vec<&str>)> = None;
+        for line in text.lines() {
+            let header = line
+                .strip_prefix("part1:")
+                .map(|rest| (1, rest))
+                .or_else(|| line.strip_prefix("part2:").map(|rest| (2, rest)));
+            match (header, &mut current) {
+                (Some((part, rest)), _) => {
+                    if let Some((part, lines)) = current.take() {
+                        answers.set(part, lines);
+                    }
+                    let rest = rest.trim();
+                    current = Some((part, if rest.is_empty() { vec![] } else { vec![rest] }));
+                }
+                (None, Some((_, lines))) => lines.push(line),
+                (None, None) => {}
+            }
+        }
+        if let Some((part, lines)) = current {
+            answers.set(part, lines);
+        }
+        answers
+    }
+
+    fn set(&mut self, part: u8, lines: Vec<&str>) {
+        let answer = lines.join("\n").trim_end().to_owned();
+        let answer = (!answer.is_empty()).then_some(answer);
+        match part {
+            1 => self.part_one = answer,
+            _ => self.part_two = answer,
+        }
+    }
+
+    /// Loads `src/answers/NN.txt`. A missing file means no answers are recorded yet.
+    pub fn load(day: DayId) -> io::Result<Answers> {
+        Answers::load_file(&day.path("answers"))
+    }
+
+    /// Loads `src/examples/answers/NN.txt`. A missing file means no answers are recorded yet.
+    pub fn load_examples(day: DayId) -> io::Result<Answers> {
+        Answers::load_file(&day.path("examples/answers"))
+    }
+
+    /// Loads `src/examples/answers/NN-<name>.txt` for the named example `name`.
+    pub fn load_named_example(day: DayId, name: &str) -> io::Result<Answers> {
+        Answers::load_file(&day.named_path("examples/answers", name))
+    }
+
+    fn load_file(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Answers::parse(&text)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Answers to check results against: the recorded ones for the real input or the
+    /// example, none for other sources.
+    pub fn try_for_source(day: DayId, source: &InputSource) -> io::Result<Answers> {
+        match source {
+            InputSource::Inputs => Answers::load(day),
+            InputSource::Examples => Answers::load_examples(day),
+            InputSource::NamedExample(name) => Answers::load_named_example(day, name),
+            InputSource::Path(_) | InputSource::Stdin => Ok(Answers::default()),
+        }
+    }
+
+    /// Like [`Answers::try_for_source`], but a file that can't be read is reported and treated as empty.
+    pub fn for_source(day: DayId, source: &InputSource) -> Answers {
+        Answers::try_for_source(day, source).unwrap_or_else(|e| {
+            eprintln!("could not read answers file: {}", e);
+            Answers::default()
+        })
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Compares answers ignoring trailing whitespace, which multi-line answers often differ in.
+pub fn matches(answer: &str, expected: &str) -> bool {
+    answer.trim_end() == expected.trim_end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            Answers::parse("part1: 24000\npart2: 45000\n"),
+            Answers {
+                part_one: Some("24000".into()),
+                part_two: Some("45000".into()),
+            }
+        );
+        assert_eq!(
+            Answers::parse("part1: CMZ\npart2:\n"),
+            Answers {
+                part_one: Some("CMZ".into()),
+                part_two: None,
+            }
+        );
+        assert_eq!(
+            Answers::parse("part1: 13140\npart2:\n##..##\n###...\n\n"),
+            Answers {
+                part_one: Some("13140".into()),
+                part_two: Some("##..##\n###...".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_examples() {
+        let answers = Answers::load_examples(5.into()).unwrap();
+        assert_eq!(answers.get(1), Some("CMZ"));
+        assert_eq!(answers.get(2), Some("MCD"));
+        assert_eq!(Answers::load_examples(25.into()).unwrap(), Answers::default());
+        let answers = Answers::load_named_example(9.into(), "larger").unwrap();
+        assert_eq!(answers.get(2), Some("36"));
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("##..##\n###...\n", "##..##\n###..."));
+        assert!(!matches("24001", "24000"));
+    }
+}
@@ -0,0 +1,475 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::process;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use crate::bench::BenchConfig;
+use crate::day::{self, DayId};
+use crate::runner::EXIT_USAGE;
+
+/// Where a day binary reads its puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/inputs/NN.txt`, the default.
+    Inputs,
+    /// `src/examples/NN.txt`, selected with `--example`.
+    Examples,
+    /// `src/examples/NN-<name>.txt`, selected with `--example-name <name>`.
+    NamedExample(String),
+    /// An arbitrary file, selected with `--input <path>` or a positional path.
+    Path(PathBuf),
+    /// Standard input, selected with `-` or `--input -`.
+    Stdin,
+}
+
+/// How much the binaries print besides the answers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// Only the answers, one per line (`-q`, `--quiet`).
+    Quiet,
+    /// Answers with their timings, the default.
+    #[default]
+    Normal,
+    /// Additionally the input path and size (`-v`, `--verbose`).
+    Verbose,
+}
+
+/// Command line arguments shared by all day binaries.
+///
+/// ```sh
+/// cargo solve 07 -- --input path/to/file
+/// cat input | cargo solve 07 -- -
+/// cargo solve 07 -- --example
+/// cargo solve 09 -- --example-name larger
+/// cargo solve 07 -- --json
+/// cargo solve 07 -- --quiet
+/// cargo solve 07 --release -- --bench --bench-runs 500 --bench-time 2
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputSource,
+    /// Print one JSON line per part instead of banners (`--json`).
+    pub json: bool,
+    /// Time each part repeatedly (`--bench`, tuned with `--bench-runs <n>` and `--bench-time <seconds>`).
+    pub bench: Option<BenchConfig>,
+    /// Give up on a part after this many seconds (`--timeout <seconds>`).
+    pub timeout: Option<Duration>,
+    /// Run only this part (`--part <1|2>`). Both parts run when `None`.
+    pub part: Option<u8>,
+    pub verbosity: Verbosity,
+}
+
+impl DayArgs {
+    pub fn parse(args: Vec<OsString>) -> Result<DayArgs, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_vec(args);
+        let example = args.contains(["-e", "--example"]);
+        let example_name: Option<String> = args.opt_value_from_str("--example-name")?;
+        let json = args.contains("--json");
+        let verbosity = parse_verbosity(&mut args)?;
+        let bench_flag = args.contains("--bench");
+        let bench_runs: Option<u32> = args.opt_value_from_str("--bench-runs")?;
+        let bench_time = args.opt_value_from_fn("--bench-time", parse_seconds)?;
+        let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+        let input_path: Option<PathBuf> = args.opt_value_from_os_str(["-i", "--input"], parse_path)?;
+        let free_path: Option<PathBuf> = args.opt_free_from_os_str(parse_free_path)?;
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unexpected arguments {:?}", remaining),
+            });
+        }
+
+        let path = input_path.or(free_path);
+        if (example || example_name.is_some()) && path.is_some() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--example can't be combined with an input path".into(),
+            });
+        }
+        let input = match (example, example_name, path) {
+            (true, Some(_), _) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: "--example can't be combined with --example-name".into(),
+                })
+            }
+            (true, None, _) => InputSource::Examples,
+            (false, Some(name), _) => InputSource::NamedExample(name),
+            (false, None, None) => InputSource::Inputs,
+            (false, None, Some(path)) if path.as_os_str() == "-" => InputSource::Stdin,
+            (false, None, Some(path)) => InputSource::Path(path),
+        };
+        let bench = (bench_flag || bench_runs.is_some() || bench_time.is_some()).then(|| {
+            let default = BenchConfig::default();
+            BenchConfig {
+                max_runs: bench_runs.unwrap_or(default.max_runs).max(1),
+                budget: bench_time.unwrap_or(default.budget),
+            }
+        });
+        Ok(DayArgs {
+            input,
+            json,
+            bench,
+            timeout,
+            part,
+            verbosity,
+        })
+    }
+
+    /// Arguments of the current process, parsed once. Exits with a usage message on bad arguments.
+    pub fn get() -> &'static DayArgs {
+        static ARGS: OnceLock<DayArgs> = OnceLock::new();
+        ARGS.get_or_init(|| match DayArgs::parse(std::env::args_os().skip(1).collect()) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                eprintln!("usage: cargo solve <day> -- [--example | --example-name <name> | --input <path> | -] [--json] [--quiet | --verbose] [--part <1|2>] [--bench [--bench-runs <n>] [--bench-time <seconds>]] [--timeout <seconds>]");
+                process::exit(EXIT_USAGE);
+            }
+        })
+    }
+}
+
+/// How `cargo all` prints the results of the real inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// A banner per day followed by its parts, the default.
+    Pretty,
+    /// A summary table for the terminal (`--format table`).
+    Table,
+    /// The summary table as Markdown (`--format markdown`).
+    Markdown,
+}
+
+/// Command line arguments of the aggregate runner, `cargo all`.
+///
+/// ```sh
+/// cargo all -- --days 3,7,12-15 --part 2
+/// cargo all -- --year 2022
+/// cargo all --release -- --only-solved --jobs 4 --timeout 10
+/// cargo all -- --examples --quiet
+/// cargo all --release -- --format table --update-readme
+/// cargo all --release -- --compare --threshold 20 --record
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllArgs {
+    /// Days to run, in ascending order (`--days <list>`). All 25 by default,
+    /// of the year given with `--year <year>` if any.
+    pub days: Vec<DayId>,
+    /// Run on the examples and print a pass/fail table (`--examples`).
+    pub examples: bool,
+    /// Run each day's binary in its own process (`--isolate`).
+    pub isolate: bool,
+    /// Leave out days that have no solution instead of printing "Not solved." (`--only-solved`).
+    pub only_solved: bool,
+    /// Run only this part of each day (`--part <1|2>`).
+    pub part: Option<u8>,
+    /// Number of days to solve at the same time (`--jobs <n>`, `0` for one per core).
+    pub jobs: usize,
+    /// Give up on a part after this many seconds (`--timeout <seconds>`).
+    pub timeout: Option<Duration>,
+    pub format: ReportFormat,
+    pub verbosity: Verbosity,
+    /// Write the summary table to the marked section of README.md (`--update-readme`).
+    pub update_readme: bool,
+    /// Append the timings of this run to the history (`--record`).
+    pub record: bool,
+    /// Compare the timings with the latest recorded ones (`--compare`).
+    pub compare: bool,
+    /// Percentage by which a part may get slower before `--compare` fails (`--threshold <percent>`).
+    pub threshold: f64,
+    /// History file to use instead of `benchmarks/history.jsonl` (`--history <path>`).
+    pub history: Option<PathBuf>,
+}
+
+impl AllArgs {
+    pub fn parse(args: Vec<OsString>) -> Result<AllArgs, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_vec(args);
+        let year = args.opt_value_from_fn(["-y", "--year"], day::parse_year)?;
+        let days: Option<String> = args.opt_value_from_str("--days")?;
+        let examples = args.contains("--examples");
+        let isolate = args.contains("--isolate");
+        let only_solved = args.contains("--only-solved");
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+        let jobs = match args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1) {
+            0 => thread::available_parallelism().map_or(1, usize::from),
+            jobs => jobs,
+        };
+        let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+        let format = args.opt_value_from_fn("--format", parse_format)?;
+        let verbosity = parse_verbosity(&mut args)?;
+        let update_readme = args.contains("--update-readme");
+        let record = args.contains("--record");
+        let compare = args.contains("--compare");
+        let threshold = args.opt_value_from_fn("--threshold", parse_threshold)?.unwrap_or(10_f64);
+        let history = args.opt_value_from_os_str("--history", parse_path)?;
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unexpected arguments {:?}", remaining),
+            });
+        }
+        if (record || compare) && jobs > 1 {
+            // days running at the same time slow each other down.
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--record and --compare can't be combined with --jobs".into(),
+            });
+        }
+        // the days can only be parsed once the year is known.
+        let days = match days {
+            Some(spec) => parse_days(&spec, year)
+                .map_err(|cause| pico_args::Error::Utf8ArgumentParsingFailed { value: spec, cause })?,
+            None => (1..=25).map(|day| DayId::new(year, day)).collect(),
+        };
+        Ok(AllArgs {
+            days,
+            examples,
+            isolate,
+            only_solved,
+            part,
+            jobs,
+            timeout,
+            format: format.unwrap_or(ReportFormat::Pretty),
+            verbosity,
+            update_readme,
+            record,
+            compare,
+            threshold,
+            history,
+        })
+    }
+
+    /// Arguments of the current process. Exits with a usage message on bad arguments.
+    pub fn get() -> AllArgs {
+        match AllArgs::parse(std::env::args_os().skip(1).collect()) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                eprintln!("usage: cargo all -- [--examples] [--isolate] [--year <year>] [--days <list>] [--only-solved] [--part <1|2>] [--jobs <n>] [--timeout <seconds>] [--format pretty|table|markdown] [--quiet | --verbose] [--update-readme] [--record] [--compare [--threshold <percent>]] [--history <path>]");
+                process::exit(EXIT_USAGE);
+            }
+        }
+    }
+}
+
+/// Parses a list of days like `3,7,12-15` into ascending, distinct days of `year`.
+/// Items can name another year, e.g. `2022/7` or `2022/1-5`.
+pub fn parse_days(spec: &str, year: Option<u16>) -> Result<Vec<DayId>, String> {
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        let (year, range) = match item.split_once('/') {
+            Some((year, range)) => (Some(day::parse_year(year)?), range),
+            None => (year, item),
+        };
+        match range.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (day::parse_day(first)?, day::parse_day(last)?);
+                if first > last {
+                    return Err(format!("{:?} is an empty range", item));
+                }
+                days.extend((first..=last).map(|day| DayId::new(year, day)));
+            }
+            None => days.push(DayId::new(year, day::parse_day(range)?)),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_verbosity(args: &mut pico_args::Arguments) -> Result<Verbosity, pico_args::Error> {
+    match (args.contains(["-q", "--quiet"]), args.contains(["-v", "--verbose"])) {
+        (true, true) => Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--quiet can't be combined with --verbose".into(),
+        }),
+        (true, false) => Ok(Verbosity::Quiet),
+        (false, true) => Ok(Verbosity::Verbose),
+        (false, false) => Ok(Verbosity::Normal),
+    }
+}
+
+fn parse_format(arg: &str) -> Result<ReportFormat, String> {
+    match arg {
+        "pretty" => Ok(ReportFormat::Pretty),
+        "table" => Ok(ReportFormat::Table),
+        "markdown" => Ok(ReportFormat::Markdown),
+        _ => Err(format!("{:?} is not a format, expected pretty, table or markdown", arg)),
+    }
+}
+
+fn parse_part(arg: &str) -> Result<u8, String> {
+    match arg {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("{:?} is not a part, expected 1 or 2", arg)),
+    }
+}
+
+/// Parses a number of seconds like `2.5`, which can't be negative.
+fn parse_seconds(arg: &str) -> Result<Duration, String> {
+    let seconds: f64 = arg
+        .parse()
+        .map_err(|_| format!("{:?} is not a number of seconds", arg))?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("{:?} is not a number of seconds of 0 or more", arg))
+}
+
+/// Parses a timeout like `2.5`, which must be more than 0 seconds, as every part would time out.
+fn parse_timeout(arg: &str) -> Result<Duration, String> {
+    match parse_seconds(arg) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!("{:?} is not a number of seconds above 0", arg)),
+    }
+}
+
+/// Parses a percentage like `12.5`, which can't be negative.
+fn parse_threshold(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(percent) if percent.is_finite() && percent >= 0.0 => Ok(percent),
+        _ => Err(format!("{:?} is not a percentage of 0 or more", arg)),
+    }
+}
+
+fn parse_path(arg: &OsStr) -> Result<PathBuf, &'static str> {
+    Ok(PathBuf::from(arg))
+}
+
+fn parse_free_path(arg: &OsStr) -> Result<PathBuf, &'static str> {
+    match arg.to_str() {
+        Some(flag) if flag.starts_with('-') && flag != "-" => Err("unknown flag"),
+        _ => parse_path(arg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<DayArgs, pico_args::Error> {
+        DayArgs::parse(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!(parse(&[]).unwrap().input, InputSource::Inputs);
+        assert_eq!(parse(&["--example"]).unwrap().input, InputSource::Examples);
+        assert_eq!(parse(&["-"]).unwrap().input, InputSource::Stdin);
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, InputSource::Stdin);
+        assert_eq!(
+            parse(&["--input", "a/b.txt"]).unwrap().input,
+            InputSource::Path("a/b.txt".into())
+        );
+        assert_eq!(
+            parse(&["a/b.txt"]).unwrap().input,
+            InputSource::Path("a/b.txt".into())
+        );
+        assert!(parse(&["--example", "a.txt"]).is_err());
+        assert_eq!(
+            parse(&["--example-name", "larger"]).unwrap().input,
+            InputSource::NamedExample("larger".into())
+        );
+        assert!(parse(&["--example-name", "larger", "--example"]).is_err());
+        assert!(parse(&["--example-name", "larger", "-"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+        assert!(parse(&["--json", "-"]).unwrap().json);
+        assert_eq!(parse(&["--part", "2"]).unwrap().part, Some(2));
+        assert!(parse(&["--part", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(parse(&[]).unwrap().verbosity, Verbosity::Normal);
+        assert_eq!(parse(&["-q", "--example"]).unwrap().verbosity, Verbosity::Quiet);
+        assert_eq!(parse(&["--verbose"]).unwrap().verbosity, Verbosity::Verbose);
+        assert!(parse(&["--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse(&[]).unwrap().bench, None);
+        assert_eq!(parse(&["--bench"]).unwrap().bench, Some(BenchConfig::default()));
+        assert_eq!(
+            parse(&["--bench-runs", "50", "--bench-time", "0.5"]).unwrap().bench,
+            Some(BenchConfig {
+                max_runs: 50,
+                budget: Duration::from_millis(500),
+            })
+        );
+        assert!(parse(&["--bench-time", "soon"]).is_err());
+        assert!(parse(&["--bench-time", "nan"]).is_err());
+        assert!(parse(&["--bench-time", "-0.5"]).is_err());
+        assert_eq!(
+            parse(&["--timeout", "2.5"]).unwrap().timeout,
+            Some(Duration::from_millis(2500))
+        );
+        assert!(parse(&["--timeout", "-1"]).is_err());
+        assert!(parse(&["--timeout", "NaN"]).is_err());
+        assert!(parse(&["--timeout", "inf"]).is_err());
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "0.0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        let days = |days: &[u8]| days.iter().map(|&day| DayId::from(day)).collect::<Vec<_>>();
+        assert_eq!(parse_days("3,7,12-15", None), Ok(days(&[3, 7, 12, 13, 14, 15])));
+        assert_eq!(parse_days("5, 1-2,2", None), Ok(days(&[1, 2, 5])));
+        assert!(parse_days("0", None).is_err());
+        assert!(parse_days("26", None).is_err());
+        assert!(parse_days("7-3", None).is_err());
+        assert!(parse_days("3,", None).is_err());
+
+        assert_eq!(
+            parse_days("2023/2,1,2022/24-25", Some(2015)),
+            Ok(vec![
+                DayId::from((2015, 1)),
+                DayId::from((2022, 24)),
+                DayId::from((2022, 25)),
+                DayId::from((2023, 2)),
+            ])
+        );
+        assert!(parse_days("15/1", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_all_args() {
+        let parse = |args: &[&str]| AllArgs::parse(args.iter().map(OsString::from).collect());
+        let defaults = parse(&[]).unwrap();
+        assert_eq!(defaults.days, (1..=25).map(DayId::from).collect::<Vec<_>>());
+        assert_eq!((defaults.only_solved, defaults.part, defaults.jobs), (false, None, 1));
+
+        let args = parse(&["--days", "12-13", "--only-solved", "--part", "2", "-j", "3"]).unwrap();
+        assert_eq!(args.days, vec![DayId::from(12), DayId::from(13)]);
+        assert!(!args.examples && !args.isolate);
+        assert!(args.only_solved);
+        assert_eq!(args.part, Some(2));
+        assert_eq!(args.jobs, 3);
+
+        let args = parse(&["--year", "2022", "--days", "12,2023/1"]).unwrap();
+        assert_eq!(args.days, vec![DayId::from((2022, 12)), DayId::from((2023, 1))]);
+        assert_eq!(parse(&["--year", "2022"]).unwrap().days.len(), 25);
+
+        assert!(parse(&["--examples"]).unwrap().examples);
+        assert_eq!(defaults.format, ReportFormat::Pretty);
+        assert_eq!(parse(&["--format", "markdown"]).unwrap().format, ReportFormat::Markdown);
+        assert!(parse(&["--format", "html"]).is_err());
+        assert_eq!(parse(&["-q"]).unwrap().verbosity, Verbosity::Quiet);
+        assert_eq!(defaults.threshold, 10_f64);
+        let args = parse(&["--compare", "--threshold", "25", "--history", "h.jsonl"]).unwrap();
+        assert!(args.compare && !args.record);
+        assert_eq!(args.threshold, 25_f64);
+        assert_eq!(args.history, Some(PathBuf::from("h.jsonl")));
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--timeout", "-1"]).is_err());
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--threshold", "-5"]).is_err());
+        assert!(parse(&["--record", "--jobs", "4"]).is_err());
+        assert!(parse(&["--compare", "--jobs", "1"]).is_ok());
+        assert!(parse(&["--threshold", "inf"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+}
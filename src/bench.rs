@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+/// Settings for `--bench`: repeat a part until `max_runs` or `budget` is reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub max_runs: u32,
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> BenchConfig {
+        BenchConfig {
+            max_runs: 10_000,
+            budget: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Summary of repeated timings of one part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(samples: &mut [Duration]) -> BenchStats {
+        assert!(!samples.is_empty());
+        samples.sort();
+        let n = samples.len();
+        let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        // nearest-rank percentile
+        let p95_rank = ((n as f64 * 0.95).ceil() as usize).max(1);
+        BenchStats {
+            runs: n as u32,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95: samples[p95_rank - 1],
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Times `func` repeatedly after a short warm-up.
+///
+/// The warm-up takes at most three runs or a tenth of the budget. Measurement then
+/// continues until `max_runs` samples are taken or the budget is used up, with at least one sample.
+pub fn bench<T>(config: &BenchConfig, mut func: impl FnMut() -> T) -> BenchStats {
+    let warmup_budget = config.budget / 10;
+    let warmup_start = Instant::now();
+    for _ in 0..3 {
+        std::hint::black_box(func());
+        if warmup_start.elapsed() >= warmup_budget {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty()
+        || (samples.len() < config.max_runs as usize && start.elapsed() < config.budget)
+    {
+        let timer = Instant::now();
+        std::hint::black_box(func());
+        samples.push(timer.elapsed());
+    }
+    BenchStats::from_samples(&mut samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let mut samples: Vec<Duration> = [5, 1, 4, 2, 3, 100]
+            .iter()
+            .map(|&us| Duration::from_micros(us))
+            .collect();
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.runs, 6);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(3500));
+        assert_eq!(stats.mean, Duration::from_nanos(19_167));
+        assert_eq!(stats.p95, Duration::from_micros(100));
+        assert_eq!(stats.std_dev, Duration::from_nanos(39_625));
+    }
+
+    #[test]
+    fn test_bench_respects_max_runs() {
+        let config = BenchConfig {
+            max_runs: 7,
+            budget: Duration::from_secs(10),
+        };
+        let mut calls = 0;
+        let stats = bench(&config, || calls += 1);
+        assert_eq!(stats.runs, 7);
+        assert!(calls >= 8);
+    }
+}
@@ -2,104 +2,75 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use std::fs;
+use std::process;
+
+use advent_of_code::day::{self, DayId};
+use advent_of_code::download::{self, fetch_input, session_token, write_atomically};
 
 struct Args {
-    day: u8,
-    year: Option<i16>,
+    id: DayId,
+    base_url: Option<String>,
+    force: bool,
 }
 
+/// The day to download, e.g. `7`, or `2022/7` or `7 --year 2022` for a day of a year.
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
-}
-
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+    let year: Option<u16> = args.opt_value_from_fn(["-y", "--year"], day::parse_year)?;
+    let base_url = args.opt_value_from_str("--base-url")?;
+    let force = args.contains("--force");
+    let id: DayId = args.free_from_str()?;
+    let id = id
+        .in_year(year)
+        .map_err(|cause| pico_args::Error::ArgumentParsingFailed { cause })?;
+    Ok(Args { id, base_url, force })
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
-    let args = match parse_args() {
+    let Args { id, base_url, force } = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
+    let base_url = base_url.unwrap_or_else(download::base_url);
+    let year = id.year.unwrap_or(download::DEFAULT_YEAR);
+    let input_path = id.path("inputs");
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    // scaffold creates empty inputs, anything else is a downloaded or pasted input.
+    if !force && fs::metadata(&input_path).is_ok_and(|metadata| metadata.len() > 0) {
+        eprintln!(
+            "\"{}\" already holds an input, use --force to replace it.",
+            id.relative_path("inputs").display()
+        );
+        process::exit(1);
     }
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
+    let session = match session_token() {
+        Ok(session) => session,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("{}", e);
+            process::exit(1);
         }
-    }
+    };
+    let user_agent = download::user_agent().unwrap_or_else(|| {
+        eprintln!("Set AOC_USER_AGENT to your repository and contact, as the Advent of Code website asks of automated requests.");
+        download::DEFAULT_USER_AGENT.to_owned()
+    });
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
+    println!("Downloading input from {}", download::input_url(&base_url, year, id.day));
+    let result = fetch_input(&base_url, &user_agent, &session, year, id.day)
+        .and_then(|input| write_atomically(&input_path, &input));
+    match result {
+        Ok(()) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            println!("🎄 Successfully wrote input to \"{}\".", id.relative_path("inputs").display());
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
     }
 }
@@ -3,130 +3,573 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, OpenOptions},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"use nom::{
-    character::complete::char,
-    sequence::terminated,
-    multi::many1,
-    IResult,
-};
+use advent_of_code::args;
+use advent_of_code::day::{self, DayId};
 
-use advent_of_code::helpers::int_parser;
+/// The module template used without `--template`.
+const DEFAULT_TEMPLATE: &str = "lines";
 
-type Input = Vec<u32>;
+const BIN_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::runner::main(&advent_of_code::days::{{module}}::Day{{day_padded}});
+}
+"###;
 
-fn input_parser(input: &str) -> IResult<&str, Input> {
-    many1(terminated(
-        int_parser,
-        char('\n'),
-    ))(input)
+struct Args {
+    days: Vec<DayId>,
+    /// Whether several days were asked for, which skips days that already exist.
+    batch: bool,
+    template: String,
+    title: Option<String>,
+    force: bool,
+    dry_run: bool,
 }
 
-pub fn part_one(input: &Input) -> Option<u32> {
-    None
+/// `relative` inside this crate, so scaffold works from any directory.
+fn crate_path(relative: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
 }
 
-pub fn part_two(input: &Input) -> Option<u32> {
-    None
+/// `path` relative to this crate, for messages.
+fn short(path: &Path) -> std::path::Display<'_> {
+    path.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(path).display()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file_nom("inputs", DAY, input_parser);
-    advent_of_code::solve_nom!(1, part_one, input);
-    advent_of_code::solve_nom!(2, part_two, input);
+/// Path of the module template `templates/<name>.rs.tmpl`.
+fn template_path(name: &str) -> PathBuf {
+    crate_path("templates").join(format!("{}.rs.tmpl", name))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Names of the module templates in `templates/`, sorted.
+fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(crate_path("templates"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|file| Some(file.strip_suffix(".rs.tmpl")?.to_owned()))
+        .collect();
+    names.sort();
+    names
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file_nom("examples", DAY, input_parser);
-        assert_eq!(part_one(&input), None);
+fn load_template(name: &str) -> Result<String, String> {
+    let path = template_path(name);
+    fs::read_to_string(&path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => format!(
+            "no template named {:?} in \"templates\", available: {}",
+            name,
+            template_names().join(", ")
+        ),
+        _ => format!("could not read template \"{}\": {}", short(&path), e),
+    })
+}
+
+/// Path of the day's module below `days`: `day07` or `y2022::day07`.
+fn module_path(id: DayId) -> String {
+    match id.year {
+        Some(year) => format!("y{}::day{:02}", year, id.day),
+        None => format!("day{:02}", id.day),
     }
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file_nom("examples", DAY, input_parser);
-        assert_eq!(part_two(&input), None);
+/// The day's module, `src/days/day07.rs` or `src/days/y2022/day07.rs`.
+fn module_file(id: DayId) -> PathBuf {
+    match id.year {
+        Some(year) => crate_path(format!("src/days/y{}/day{:02}.rs", year, id.day)),
+        None => crate_path(format!("src/days/day{:02}.rs", id.day)),
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+/// Fills in the placeholders of a template:
+///
+/// | Placeholder | Day 7 | Day 7 of 2022 |
+/// | --- | --- | --- |
+/// | `{{day}}` | `7` | `7` |
+/// | `{{day_padded}}` | `07` | `07` |
+/// | `{{year}}` | | `2022` |
+/// | `{{year_const}}` | | `const YEAR` of the solution |
+/// | `{{day_id}}` | `7` | `(2022, 7)` |
+/// | `{{module}}` | `day07` | `y2022::day07` |
+/// | `{{title}}` | `Day 7`, or `Day 7: No Space Left On Device` with `--title` | |
+fn render(template: &str, id: DayId, title: Option<&str>) -> String {
+    let (day_id, year, year_const) = match id.year {
+        Some(year) => (
+            format!("({}, {})", year, id.day),
+            year.to_string(),
+            format!("\n    const YEAR: Option<u16> = Some({});", year),
+        ),
+        None => (id.day.to_string(), String::new(), String::new()),
+    };
+    let title = match title {
+        Some(title) => format!("Day {}: {}", id.day, title),
+        None => format!("Day {}", id.day),
+    };
+    template
+        .replace("{{module}}", &module_path(id))
+        .replace("{{day_id}}", &day_id)
+        .replace("{{year_const}}", &year_const)
+        .replace("{{year}}", &year)
+        .replace("{{title}}", &title)
+        .replace("{{day_padded}}", &format!("{:02}", id.day))
+        .replace("{{day}}", &id.day.to_string())
+}
+
+/// The days to scaffold: one day like `7`, `2022/7` or `2022-07`, a list of days like
+/// `16-25` or `2022/1-5,2022/9`, or `--all-missing` for every day of `--year` without a module.
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let year: Option<u16> = args.opt_value_from_fn(["-y", "--year"], day::parse_year)?;
+    let template = args
+        .opt_value_from_str(["-t", "--template"])?
+        .unwrap_or_else(|| DEFAULT_TEMPLATE.to_owned());
+    let title = args.opt_value_from_str("--title")?;
+    let force = args.contains("--force");
+    let dry_run = args.contains("--dry-run");
+    let all_missing = args.contains("--all-missing");
+    let spec: Option<String> = args.opt_free_from_str()?;
+
+    let failed = |cause: String| pico_args::Error::ArgumentParsingFailed { cause };
+    let (days, batch) = match (spec, all_missing) {
+        (Some(_), true) => return Err(failed("--all-missing can't be combined with days".into())),
+        (None, true) => {
+            let days = (1..=25)
+                .map(|day| DayId::new(year, day))
+                .filter(|&id| !module_file(id).exists())
+                .collect();
+            (days, true)
+        }
+        (Some(spec), false) => match spec.parse::<DayId>() {
+            Ok(id) => (vec![id.in_year(year).map_err(failed)?], false),
+            Err(_) => {
+                let days = args::parse_days(&spec, year).map_err(failed)?;
+                let batch = days.len() > 1;
+                (days, batch)
+            }
+        },
+        (None, false) => return Err(pico_args::Error::MissingArgument),
+    };
+    Ok(Args { days, batch, template, title, force, dry_run })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    /// Rendered from a template, only replaced with `--force`.
+    Template,
+    /// Puzzle input or answers, never replaced.
+    Data,
+    /// A module list the day is added to, updated in place.
+    Registry,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// A file scaffold writes.
+struct Step {
+    name: &'static str,
+    path: PathBuf,
+    contents: String,
+    kind: Kind,
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Create,
+    Overwrite,
+    Keep,
+}
+
+impl Step {
+    fn new(name: &'static str, path: PathBuf, contents: String, kind: Kind) -> Step {
+        Step {
+            name,
+            path,
+            contents,
+            kind,
         }
-    };
+    }
 
-    let day_padded = format!("{:02}", day);
+    /// What applying the step does. Fails if a template file exists and `force` isn't set.
+    fn action(&self, force: bool) -> Result<Action, String> {
+        if !self.path.exists() {
+            return Ok(Action::Create);
+        }
+        match self.kind {
+            Kind::Data => Ok(Action::Keep),
+            Kind::Registry if fs::read_to_string(&self.path).is_ok_and(|text| text == self.contents) => Ok(Action::Keep),
+            Kind::Registry => Ok(Action::Overwrite),
+            Kind::Template if force => Ok(Action::Overwrite),
+            Kind::Template => Err(format!(
+                "{} file \"{}\" already exists, use --force to overwrite it",
+                self.name,
+                short(&self.path)
+            )),
+        }
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    fn describe(&self, action: Action, dry_run: bool) -> String {
+        let verb = match (action, self.kind, dry_run) {
+            (Action::Create, _, false) => "Created",
+            (Action::Create, _, true) => "Would create",
+            (Action::Overwrite, Kind::Registry, false) => "Updated",
+            (Action::Overwrite, Kind::Registry, true) => "Would update",
+            (Action::Overwrite, _, false) => "Overwrote",
+            (Action::Overwrite, _, true) => "Would overwrite",
+            (Action::Keep, _, false) => "Kept existing",
+            (Action::Keep, _, true) => "Would keep existing",
+        };
+        let empty = if action == Action::Create && self.contents.is_empty() { "empty " } else { "" };
+        format!("{} {}{} file \"{}\"", verb, empty, self.name, short(&self.path))
+    }
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
+/// Records what scaffold changed so a failed run can be undone.
+#[derive(Default)]
+struct Rollback {
+    created_files: Vec<PathBuf>,
+    created_dirs: Vec<PathBuf>,
+    replaced: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl Rollback {
+    fn apply(&mut self, step: &Step, action: Action) -> io::Result<()> {
+        match action {
+            Action::Keep => Ok(()),
+            Action::Create => {
+                self.create_parent(&step.path)?;
+                let mut file = OpenOptions::new().write(true).create_new(true).open(&step.path)?;
+                self.created_files.push(step.path.clone());
+                file.write_all(step.contents.as_bytes())
+            }
+            Action::Overwrite => {
+                let original = fs::read(&step.path)?;
+                self.replaced.push((step.path.clone(), original));
+                fs::write(&step.path, &step.contents)
+            }
         }
-    };
+    }
+
+    fn create_parent(&mut self, path: &Path) -> io::Result<()> {
+        let parent = match path.parent() {
+            Some(parent) => parent,
+            None => return Ok(()),
+        };
+        let missing: Vec<PathBuf> = parent
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .map(Path::to_owned)
+            .collect();
+        fs::create_dir_all(parent)?;
+        // innermost first, the order in which they can be removed.
+        self.created_dirs.extend(missing);
+        Ok(())
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    /// Restores overwritten files and removes the files and folders created, newest first.
+    fn undo(self) {
+        for (path, original) in self.replaced.into_iter().rev() {
+            if let Err(e) = fs::write(&path, original) {
+                eprintln!("Failed to restore \"{}\": {}", short(&path), e);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        for path in self.created_files.into_iter().rev() {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!("Failed to remove \"{}\": {}", short(&path), e);
+            }
         }
+        for dir in self.created_dirs {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+/// Checks every step before touching anything, then applies them. If a step fails, the
+/// steps applied so far are undone and the error is returned.
+fn run(steps: &[Step], force: bool, dry_run: bool) -> Result<Vec<String>, String> {
+    let actions = steps
+        .iter()
+        .map(|step| step.action(force))
+        .collect::<Result<Vec<Action>, String>>()?;
+    if dry_run {
+        return Ok(steps.iter().zip(actions).map(|(step, action)| step.describe(action, true)).collect());
+    }
+
+    let mut rollback = Rollback::default();
+    let mut done = Vec::new();
+    for (step, action) in steps.iter().zip(actions) {
+        if let Err(e) = rollback.apply(step, action) {
+            rollback.undo();
+            return Err(format!(
+                "Failed to write {} file \"{}\": {}. Undid the changes made so far.",
+                step.name,
+                short(&step.path),
+                e
+            ));
+        }
+        done.push(step.describe(action, false));
+    }
+    Ok(done)
+}
+
+/// Adds `pub mod <module>;` to the declarations of a `mod.rs`, which are kept sorted.
+fn add_module(text: &str, module: &str) -> String {
+    let declaration = format!("pub mod {};", module);
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return text.to_owned();
+    }
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > declaration.as_str())
+        .or_else(|| lines.iter().rposition(|line| line.starts_with("pub mod ")).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(position, &declaration);
+    lines.join("\n") + "\n"
+}
+
+/// Adds the solution of `id` to `ALL` in the registry, which is ordered by year and day.
+fn add_to_all(text: &str, id: DayId) -> Result<String, String> {
+    let entry = format!("    &{}::Day{:02},", module_path(id), id.day);
+    let mut lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static ALL"))
+        .ok_or("found no `pub static ALL` to add the day to")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or("found no end of `ALL`")?;
+    if lines[start..end].contains(&entry.as_str()) {
+        return Ok(text.to_owned());
+    }
+    let position = (start + 1..end)
+        .find(|&i| entry_id(lines[i]).is_some_and(|other| other > id))
+        .unwrap_or(end);
+    lines.insert(position, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The day of an entry of `ALL`, `&day07::Day07,` or `&y2022::day07::Day07,`.
+fn entry_id(line: &str) -> Option<DayId> {
+    let mut modules = line.trim().strip_prefix('&')?.split("::");
+    let first = modules.next()?;
+    let (year, day) = match first.strip_prefix('y') {
+        Some(year) => (Some(year.parse().ok()?), modules.next()?),
+        None => (None, first),
+    };
+    Some(DayId::new(year, day.strip_prefix("day")?.parse().ok()?))
+}
+
+/// Everything a new day needs: its module and binary, empty input and example files, stubs
+/// for its answers, and its registration in `src/days/mod.rs` (and the module of its year).
+fn steps(id: DayId, module_template: &str, title: Option<&str>) -> Result<Vec<Step>, String> {
+    let day_padded = format!("{:02}", id.day);
+    let registry_path = crate_path("src/days/mod.rs");
+    let registry = fs::read_to_string(&registry_path)
+        .map_err(|e| format!("Failed to read \"{}\": {}", short(&registry_path), e))?;
+    let registry = match id.year {
+        Some(year) => add_module(&registry, &format!("y{}", year)),
+        None => add_module(&registry, &format!("day{}", day_padded)),
+    };
+    let registry = add_to_all(&registry, id)
+        .map_err(|e| format!("Failed to register the day in \"{}\": {}", short(&registry_path), e))?;
+    let answers = "part1:\npart2:\n".to_owned();
+
+    let mut steps = vec![
+        Step::new("module", module_file(id), render(module_template, id, title), Kind::Template),
+        Step::new(
+            "binary",
+            crate_path(format!("src/bin/{}.rs", id.bin_name())),
+            render(BIN_TEMPLATE, id, title),
+            Kind::Template,
+        ),
+        Step::new("input", id.path("inputs"), String::new(), Kind::Data),
+        Step::new("example", id.path("examples"), String::new(), Kind::Data),
+        Step::new("answers", id.path("answers"), answers.clone(), Kind::Data),
+        Step::new("example answers", id.path("examples/answers"), answers, Kind::Data),
+    ];
+    if let Some(year) = id.year {
+        let path = crate_path(format!("src/days/y{}/mod.rs", year));
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => format!("/*\n * Solutions of {}, one module per day.\n */\n", year),
+            Err(e) => return Err(format!("Failed to read \"{}\": {}", short(&path), e)),
+        };
+        steps.push(Step::new("year module", path, add_module(&text, &format!("day{}", day_padded)), Kind::Registry));
     }
+    steps.push(Step::new("registry", registry_path, registry, Kind::Registry));
+    Ok(steps)
+}
+
+fn print_next_steps(id: DayId) {
+    println!("🎄 The day is registered. Next:");
+    let commands = [
+        (format!("cargo download {}", id), "your puzzle input"),
+        (format!("cargo test days::{}", module_path(id)), "the tests on the example"),
+        (format!("cargo solve {} -- --example", id.bin_name()), "the example"),
+        (format!("cargo solve {}", id.bin_name()), "your input"),
+    ];
+    let width = commands.iter().map(|(command, _)| command.len()).max().unwrap_or(0);
+    for (command, note) in commands {
+        println!("    {:width$}  # {}", command, note, width = width);
+    }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+fn list(days: &[DayId]) -> String {
+    days.iter().map(DayId::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn main() {
+    let Args { days, batch, template, title, force, dry_run } = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::ArgumentParsingFailed { cause }) => {
+            eprintln!("Failed to process arguments: {}", cause);
+            process::exit(1);
+        }
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer, optionally with a year). example: `cargo scaffold 7` or `cargo scaffold 2022/7`");
+            eprintln!("Scaffold several days with a list like `cargo scaffold 16-25`, or every day without a module with `--all-missing`.");
+            eprintln!(
+                "Options: --template <name> (templates/<name>.rs.tmpl, default: {}), --title <title>, --year <year>, --dry-run, --force",
+                DEFAULT_TEMPLATE
+            );
+            process::exit(1);
         }
+    };
+    let module_template = match load_template(&template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to load module template: {}", e);
             process::exit(1);
         }
+    };
+
+    let (mut created, mut skipped, mut failed) = (Vec::new(), Vec::new(), Vec::new());
+    for &id in &days {
+        if batch && !force && module_file(id).exists() {
+            skipped.push(id);
+            continue;
+        }
+        let result = steps(id, &module_template, title.as_deref()).and_then(|steps| run(&steps, force, dry_run));
+        match result {
+            Ok(lines) => {
+                if batch {
+                    println!("{}{}:", if dry_run { "Would scaffold " } else { "" }, id);
+                }
+                for line in lines {
+                    println!("{}{}", if batch { "    " } else { "" }, line);
+                }
+                created.push(id);
+            }
+            Err(e) => {
+                eprintln!("{}{}", if batch { format!("{}: ", id) } else { String::new() }, e);
+                failed.push(id);
+            }
+        }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    if !batch {
+        if failed.is_empty() && !dry_run {
+            println!("---");
+            print_next_steps(days[0]);
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+    } else {
+        println!("---");
+        let verb = if dry_run { "Would scaffold" } else { "Scaffolded" };
+        println!("{} {} day(s){}", verb, created.len(), if created.is_empty() { String::new() } else { format!(": {}", list(&created)) });
+        if !skipped.is_empty() {
+            println!("Skipped {} existing day(s): {}", skipped.len(), list(&skipped));
+        }
+        if !failed.is_empty() {
+            println!("Failed to scaffold {} day(s): {}", failed.len(), list(&failed));
         }
+        if !created.is_empty() && !dry_run {
+            println!("🎄 Type `cargo download <day>` to get your input and `cargo solve <day>` to run a solution.");
+        }
+    }
+    if !failed.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
     }
 
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    #[test]
+    fn test_existing_files() {
+        let dir = temp_dir("existing");
+        fs::write(dir.join("07.txt"), "my input").unwrap();
+        fs::write(dir.join("day07.rs"), "my solution").unwrap();
+        let steps = [
+            Step::new("module", dir.join("day07.rs"), "template".into(), Kind::Template),
+            Step::new("input", dir.join("07.txt"), String::new(), Kind::Data),
+        ];
+
+        assert!(run(&steps, false, false).unwrap_err().contains("use --force"));
+        let lines = run(&steps, true, true).unwrap();
+        assert!(lines[0].starts_with("Would overwrite module file"));
+        assert!(lines[1].starts_with("Would keep existing input file"));
+        assert_eq!(fs::read_to_string(dir.join("day07.rs")).unwrap(), "my solution");
+
+        run(&steps, true, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("day07.rs")).unwrap(), "template");
+        assert_eq!(fs::read_to_string(dir.join("07.txt")).unwrap(), "my input");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rollback() {
+        let dir = temp_dir("rollback");
+        fs::write(dir.join("day07.rs"), "my solution").unwrap();
+        fs::write(dir.join("inputs"), "a file where a folder is needed").unwrap();
+        let steps = [
+            Step::new("module", dir.join("day07.rs"), "template".into(), Kind::Template),
+            Step::new("binary", dir.join("bin/07.rs"), "fn main() {}".into(), Kind::Template),
+            Step::new("input", dir.join("inputs/07.txt"), String::new(), Kind::Data),
+        ];
+
+        let error = run(&steps, true, false).unwrap_err();
+        assert!(error.starts_with("Failed to write input file"), "{}", error);
+        assert_eq!(fs::read_to_string(dir.join("day07.rs")).unwrap(), "my solution");
+        assert!(!dir.join("bin").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    const REGISTRY: &str = "use crate::solution::DynSolution;
+
+pub mod day01;
+pub mod day03;
+
+pub static ALL: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_register() {
+        let text = add_to_all(&add_module(REGISTRY, "day02"), 2.into()).unwrap();
+        assert!(text.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(text.contains("    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];"));
+        assert_eq!(add_to_all(&add_module(&text, "day02"), 2.into()).unwrap(), text);
+
+        let text = add_to_all(&add_module(REGISTRY, "y2022"), (2022, 2).into()).unwrap();
+        assert!(text.contains("pub mod day03;\npub mod y2022;\n"));
+        assert!(text.contains("    &day03::Day03,\n    &y2022::day02::Day02,\n];"));
+
+        assert!(add_to_all("pub mod day01;\n", 2.into()).is_err());
+        assert_eq!(entry_id("    &y2022::day07::Day07,"), Some(DayId::from((2022, 7))));
+    }
 }
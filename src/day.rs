@@ -0,0 +1,206 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::json::{Object, Value};
+
+/// Identifies a puzzle: its day and, in repositories that hold several years, its year.
+///
+/// Days without a year use the single-year layout: `src/inputs/07.txt`, `src/examples/07.txt`,
+/// `src/answers/07.txt` and the binary `src/bin/07.rs`. Days of a year get a folder per year,
+/// e.g. `src/inputs/2022/07.txt`, and the binary `src/bin/2022-07.rs`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+    pub year: Option<u16>,
+    pub day: u8,
+}
+
+impl DayId {
+    pub fn new(year: Option<u16>, day: u8) -> DayId {
+        DayId { year, day }
+    }
+
+    /// Puts a day given without a year into `year`, e.g. the one passed with `--year`.
+    /// Fails if the day names a different year.
+    pub fn in_year(self, year: Option<u16>) -> Result<DayId, String> {
+        match (self.year, year) {
+            (Some(own), Some(year)) if own != year => {
+                Err(format!("day {} is not in year {}", self, year))
+            }
+            (own, year) => Ok(DayId::new(own.or(year), self.day)),
+        }
+    }
+
+    /// `src/<folder>/NN.txt`, or `src/<folder>/<year>/NN.txt`, relative to the crate root.
+    pub fn relative_path(&self, folder: &str) -> PathBuf {
+        self.folder(folder).join(format!("{:02}.txt", self.day))
+    }
+
+    /// [`DayId::relative_path`] inside this crate, independent of the working directory.
+    pub fn path(&self, folder: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(self.relative_path(folder))
+    }
+
+    /// Like [`DayId::path`] for a named file of the day, `src/<folder>/NN-<name>.txt`.
+    pub fn named_path(&self, folder: &str, name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(self.folder(folder))
+            .join(format!("{:02}-{}.txt", self.day, name))
+    }
+
+    /// Names of the day's named files in `folder`, e.g. `larger` for `src/examples/09-larger.txt`, sorted.
+    pub fn names_in(&self, folder: &str) -> Vec<String> {
+        let prefix = format!("{:02}-", self.day);
+        let entries = match fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(self.folder(folder))) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|file| Some(file.strip_prefix(&prefix)?.strip_suffix(".txt")?.to_owned()))
+            .filter(|name| !name.is_empty())
+            .collect();
+        names.sort();
+        names
+    }
+
+    fn folder(&self, folder: &str) -> PathBuf {
+        let path = Path::new("src").join(folder);
+        match self.year {
+            Some(year) => path.join(year.to_string()),
+            None => path,
+        }
+    }
+
+    /// Name of the day's binary: `07` or `2022-07`.
+    pub fn bin_name(&self) -> String {
+        match self.year {
+            Some(year) => format!("{}-{:02}", year, self.day),
+            None => format!("{:02}", self.day),
+        }
+    }
+
+    /// Adds the `year` of days that have one and the `day` to a JSON object.
+    pub(crate) fn to_fields(self, object: Object) -> Object {
+        let object = match self.year {
+            Some(year) => object.field("year", Value::Int(year.into())),
+            None => object,
+        };
+        object.field("day", Value::Int(self.day.into()))
+    }
+
+    /// Reads the fields written by [`DayId::to_fields`].
+    pub(crate) fn from_fields(object: &Object) -> Option<DayId> {
+        let year = match object.get("year") {
+            Some(year) => Some(year.as_u64()?.try_into().ok()?),
+            None => None,
+        };
+        Some(DayId {
+            year,
+            day: object.get("day")?.as_u64()?.try_into().ok()?,
+        })
+    }
+}
+
+impl From<u8> for DayId {
+    fn from(day: u8) -> DayId {
+        DayId { year: None, day }
+    }
+}
+
+impl From<(u16, u8)> for DayId {
+    fn from((year, day): (u16, u8)) -> DayId {
+        DayId {
+            year: Some(year),
+            day,
+        }
+    }
+}
+
+/// `07`, or `2022/07` for a day of a year.
+impl fmt::Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{}/{:02}", year, self.day),
+            None => write!(f, "{:02}", self.day),
+        }
+    }
+}
+
+/// Parses `7`, `07`, `2022/07` or the binary name `2022-07`.
+impl FromStr for DayId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DayId, String> {
+        match s.split_once(['/', '-']) {
+            Some((year, day)) => Ok(DayId::new(Some(parse_year(year)?), parse_day(day)?)),
+            None => Ok(DayId::new(None, parse_day(s)?)),
+        }
+    }
+}
+
+/// Parses a day between 1 and 25.
+pub fn parse_day(day: &str) -> Result<u8, String> {
+    match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("{:?} is not a day between 1 and 25", day.trim())),
+    }
+}
+
+/// Parses a year of Advent of Code, which started in 2015.
+pub fn parse_year(year: &str) -> Result<u16, String> {
+    match year.trim().parse::<u16>() {
+        Ok(year @ 2015..=9999) => Ok(year),
+        _ => Err(format!("{:?} is not a year of Advent of Code", year.trim())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("7".parse(), Ok(DayId::from(7)));
+        assert_eq!("2022/07".parse(), Ok(DayId::from((2022, 7))));
+        assert_eq!("2022-7".parse(), Ok(DayId::from((2022, 7))));
+        assert!("26".parse::<DayId>().is_err());
+        assert!("22/07".parse::<DayId>().is_err());
+        assert_eq!(DayId::from(7).to_string(), "07");
+        assert_eq!(DayId::from((2022, 7)).to_string(), "2022/07");
+        assert_eq!(DayId::from((2022, 7)).bin_name(), "2022-07");
+        assert_eq!(DayId::from(7).in_year(Some(2022)), Ok(DayId::from((2022, 7))));
+        assert!(DayId::from((2023, 7)).in_year(Some(2022)).is_err());
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(DayId::from(7).relative_path("inputs"), Path::new("src/inputs/07.txt"));
+        assert_eq!(
+            DayId::from((2022, 7)).relative_path("examples/answers"),
+            Path::new("src/examples/answers/2022/07.txt")
+        );
+    }
+
+    #[test]
+    fn test_named_files() {
+        assert!(DayId::from(9).named_path("examples", "larger").ends_with("src/examples/09-larger.txt"));
+        assert_eq!(DayId::from(9).names_in("examples"), vec!["larger"]);
+        assert!(DayId::from(9).names_in("answers").is_empty());
+        assert!(DayId::from((2022, 9)).names_in("examples").is_empty());
+    }
+
+    #[test]
+    fn test_json_fields() {
+        let object = DayId::from((2022, 7)).to_fields(Object::new());
+        assert_eq!(object.to_line(), r#"{"year":2022,"day":7}"#);
+        assert_eq!(DayId::from_fields(&object), Some(DayId::from((2022, 7))));
+        let object = DayId::from(7).to_fields(Object::new());
+        assert_eq!(DayId::from_fields(&object), Some(DayId::from(7)));
+    }
+}
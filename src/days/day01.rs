@@ -0,0 +1,58 @@
+use nom::{
+    character::complete::{char, digit1},
+    combinator::map_res,
+    multi::{separated_list0, separated_list1},
+    IResult,
+};
+
+use std::str::FromStr;
+use crate::solution::Solution;
+
+type Input = Vec<Vec<u32>>;
+
+pub fn input_parser(input: &str) -> IResult<&str, Input> {
+    separated_list1(
+        char('\n'),
+        separated_list0(
+            char('\n'),
+            map_res(digit1, FromStr::from_str),
+        ),
+    )(input)
+}
+
+pub fn part_one(input: &Input) -> Option<u32> {
+    input.iter()
+        .map(|elf| elf.iter().sum())
+        .max()
+}
+
+pub fn part_two(input: &Input) -> Option<u32> {
+    let mut sums: Vec<u32> = input.iter()
+        .map(|elf| elf.iter().sum())
+        .collect::<Vec<u32>>();
+    sums.sort_by(|a, b| b.cmp(a));
+    Some(sums[0] + sums[1] + sums[2])
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 1, parser = input_parser, part_one = Some(24000), part_two = Some(45000));
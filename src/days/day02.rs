@@ -0,0 +1,85 @@
+use nom::{
+    branch::alt,
+    character::complete::char,
+    combinator::value,
+    sequence::{terminated, tuple},
+    multi::many1,
+    IResult,
+};
+
+use crate::solution::Solution;
+
+type Input = Vec<(i32, i32)>;
+
+fn abc_parser(input: &str) -> IResult<&str, i32> {
+    alt((
+        value(1, char('A')),
+        value(2, char('B')),
+        value(3, char('C')),
+    ))(input)
+}
+
+fn xyz_parser(input: &str) -> IResult<&str, i32> {
+    alt((
+        value(1, char('X')),
+        value(2, char('Y')),
+        value(3, char('Z')),
+    ))(input)
+}
+
+fn input_parser(input: &str) -> IResult<&str, Input> {
+    many1(terminated(
+        tuple((
+            terminated(abc_parser, char(' ')),
+            xyz_parser,
+        )),
+        char('\n'),
+    ))(input)
+}
+
+fn result(a: i32, b: i32) -> i32 {
+    ((b - a + 3) % 3 + 1) % 3 * 3 + b
+}
+
+fn result2(a: i32, b: i32) -> i32 {
+    result(a, (a + (b - 2) - 1 + 3) % 3 + 1)
+}
+
+pub fn part_one(input: &Input) -> Option<i32> {
+    let mut sum = 0;
+    for &(a, b) in input.iter() {
+        sum += result(a, b)
+    }
+    Some(sum)
+}
+
+pub fn part_two(input: &Input) -> Option<i32> {
+    let mut sum = 0;
+    for &(a, b) in input.iter() {
+        sum += result2(a, b)
+    }
+    Some(sum)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<i32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<i32> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 2, parser = input_parser, part_one = Some(15), part_two = Some(12));
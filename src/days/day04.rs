@@ -0,0 +1,78 @@
+use nom::{
+    character::complete::{char, digit1},
+    combinator::map_res,
+    sequence::{terminated, tuple},
+    multi::many1,
+    IResult,
+};
+
+use std::str::FromStr;
+use crate::solution::Solution;
+
+type Assignment = (u32, u32);
+
+type Input = Vec<(Assignment, Assignment)>;
+
+fn num_parser(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, FromStr::from_str)(input)
+}
+
+fn assignment_parser(input: &str) -> IResult<&str, Assignment> {
+    tuple((
+        terminated(num_parser, char('-')),
+        num_parser,
+    ))(input)
+}
+
+fn input_parser(input: &str) -> IResult<&str, Input> {
+    many1(terminated(
+        tuple((
+            terminated(assignment_parser, char(',')),
+            assignment_parser,
+        )),
+        char('\n'),
+    ))(input)
+}
+
+pub fn part_one(input: &Input) -> Option<u32> {
+    let mut ans = 0;
+    for &((a, b), (c, d)) in input.iter() {
+        if a <= c && b >= d || c <= a && d >= b {
+            ans += 1;
+        }
+    }
+    Some(ans)
+}
+
+pub fn part_two(input: &Input) -> Option<u32> {
+    let mut ans = 0;
+    for &((a, b), (c, d)) in input.iter() {
+        if a <= c && c <= b || a <= d && d <= b || a < c && d < b || c < a && b < d {
+            ans += 1;
+        }
+    }
+    Some(ans)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 4, parser = input_parser, part_one = Some(2), part_two = Some(4));
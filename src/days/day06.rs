@@ -0,0 +1,64 @@
+use nom::{
+    character::complete::{char, alpha1},
+    combinator::map,
+    sequence::terminated,
+    IResult,
+};
+
+use std::collections::HashSet;
+use crate::solution::Solution;
+
+type Input = Vec<char>;
+
+fn input_parser(input: &str) -> IResult<&str, Input> {
+    map(
+        terminated(
+            alpha1,
+            char('\n'),
+        ),
+        |s: &str| s.chars().collect(),
+    )(input)
+}
+
+pub fn part_one(input: &Input) -> Option<usize> {
+    for i in 0..input.len() {
+        if input[i] != input[i + 1] && input[i] != input[i + 2] && input[i] != input[i + 3] &&
+                input[i + 1] != input[i + 2] && input[i + 1] != input[i + 3] &&
+                input[i + 2] != input[i + 3] {
+            return Some(i + 4)
+        }
+    }
+    None
+}
+
+pub fn part_two(input: &Input) -> Option<usize> {
+    for i in 0..input.len() {
+        if HashSet::<&char>::from_iter(input[i..i + 14].iter()).len() == 14 {
+            return Some(i + 14)
+        }
+    }
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<usize> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 6, parser = input_parser, part_one = Some(7), part_two = Some(19));
@@ -0,0 +1,133 @@
+use nom::{
+    character::complete::{anychar, char},
+    combinator::{map, verify},
+    sequence::terminated,
+    multi::many1,
+    IResult,
+};
+use crate::solution::Solution;
+
+type Input = Vec<Vec<i8>>;
+
+fn input_parser(input: &str) -> IResult<&str, Input> {
+    many1(terminated(
+        many1(
+            map(
+                verify(anychar, |&c| c.is_ascii_digit()),
+                |c| c as i8 - '0' as i8,
+            ),
+        ),
+        char('\n'),
+    ))(input)
+}
+
+pub fn part_one(input: &Input) -> Option<usize> {
+    let mut visibility_map = vec![vec![false; input[0].len()]; input.len()];
+    for i in 0..visibility_map.len() {
+        let mut max_height = -1i8;
+        for j in 0..visibility_map[0].len() {
+            if input[i][j] > max_height {
+                max_height = input[i][j];
+                visibility_map[i][j] = true;
+            }
+        }
+    }
+    for i in 0..visibility_map.len() {
+        let mut max_height = -1i8;
+        for j in 0..visibility_map[0].len() {
+            if input[j][i] > max_height {
+                max_height = input[j][i];
+                visibility_map[j][i] = true;
+            }
+        }
+    }
+    for i in (0..visibility_map.len()).rev() {
+        let mut max_height = -1i8;
+        for j in (0..visibility_map[0].len()).rev() {
+            if input[i][j] > max_height {
+                max_height = input[i][j];
+                visibility_map[i][j] = true;
+            }
+        }
+    }
+    for i in (0..visibility_map.len()).rev() {
+        let mut max_height = -1i8;
+        for j in (0..visibility_map[0].len()).rev() {
+            if input[j][i] > max_height {
+                max_height = input[j][i];
+                visibility_map[j][i] = true;
+            }
+        }
+    }
+    Some(
+        visibility_map.iter()
+            .map(|row| row.iter().filter(|f| **f).count())
+            .sum()
+    )
+}
+
+fn scenic_score(input: &Input, row: usize, column: usize) -> usize {
+    let mut v1 = 0usize;
+    for i in (0..row).rev() {
+        v1 += 1;
+        if input[i][column] >= input[row][column] {
+            break;
+        }
+    }
+    let mut v2 = 0usize;
+    for j in (0..column).rev() {
+        v2 += 1;
+        if input[row][j] >= input[row][column] {
+            break;
+        }
+    }
+    let mut v3 = 0usize;
+    for i in row + 1..input.len() {
+        v3 += 1;
+        if input[i][column] >= input[row][column] {
+            break;
+        }
+    }
+    let mut v4 = 0usize;
+    for j in column + 1..input[0].len() {
+        v4 += 1;
+        if input[row][j] >= input[row][column] {
+            break;
+        }
+    }
+    v1 * v2 * v3 * v4
+}
+
+pub fn part_two(input: &Input) -> Option<usize> {
+    let mut max_score = 0;
+    for i in 0..input.len() {
+        for j in 0..input[0].len() {
+            let score = scenic_score(input, i, j);
+            max_score = std::cmp::max(max_score, score);
+        }
+    }
+    Some(max_score)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<usize> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 8, parser = input_parser, part_one = Some(21), part_two = Some(8));
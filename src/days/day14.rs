@@ -0,0 +1,224 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::map,
+    sequence::{separated_pair, terminated},
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+use crate::helpers::int_parser;
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Point(i32, i32);
+
+type Path = Vec<Point>;
+
+type Input = Vec<Path>;
+
+fn point_parser(input: &str) -> IResult<&str, Point> {
+    map(
+        separated_pair(
+            int_parser,
+            char(','),
+            int_parser,
+        ),
+        |(p1, p2)| Point(p1, p2),
+    )(input)
+}
+
+fn path_parser(input: &str) -> IResult<&str, Path> {
+    separated_list1(
+        tag(" -> "),
+        point_parser,
+    )(input)
+}
+
+fn input_parser(input: &str) -> IResult<&str, Input> {
+    many1(terminated(
+        path_parser,
+        char('\n'),
+    ))(input)
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Pixel {
+    Air, Stone, Sand
+}
+
+pub fn part_one(input: &Input) -> Option<u32> {
+    let min_x = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.0)
+             .min()
+             .unwrap()
+         )
+        .min()
+        .unwrap();
+    let min_y = 0;
+    let max_x = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.0)
+             .max()
+             .unwrap()
+         )
+        .max()
+        .unwrap();
+    let max_y = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.1)
+             .max()
+             .unwrap()
+         )
+        .max()
+        .unwrap();
+    let width = max_x - min_x + 1;
+    let height = max_y - min_y + 1;
+    let mut field = vec![vec![Pixel::Air; height as usize]; width as usize];
+    for path in input {
+        for i in 1..path.len() {
+            let point0 = &path[i - 1];
+            let point1 = &path[i];
+            let dx = point1.0.cmp(&point0.0) as i32;
+            let dy = point1.1.cmp(&point0.1) as i32;
+            let mut point = point0.clone();
+            loop {
+                field[(point.0 - min_x) as usize][(point.1 - min_y) as usize] = Pixel::Stone;
+                if point == *point1 {
+                    break;
+                }
+                point.0 += dx;
+                point.1 += dy;
+            }
+        }
+    }
+    let sand_start = Point(500 - min_x, 0);
+    let mut sand_count = 0;
+    'outer: loop {
+        let mut sand = sand_start.clone();
+        while sand.0 >= 0 && sand.0 < width && sand.1 < height {
+            if field[sand.0 as usize][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+            } else if sand.0 == 0 ||
+                    field[sand.0 as usize - 1][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+                sand.0 -= 1;
+            } else if sand.0 == width - 1 ||
+                    field[sand.0 as usize + 1][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+                sand.0 += 1;
+            } else {
+                field[sand.0 as usize][sand.1 as usize] = Pixel::Sand;
+                sand_count += 1;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    Some(sand_count)
+}
+
+pub fn part_two(input: &Input) -> Option<u32> {
+    let min_y = 0;
+    let max_y = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.1)
+             .max()
+             .unwrap()
+         )
+        .max()
+        .unwrap() + 2;
+    let height = max_y - min_y + 1;
+    let min_x = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.0)
+             .min()
+             .unwrap()
+         )
+        .min()
+        .unwrap()
+        .min(500 - height);
+    let max_x = input.iter()
+        .map(|path| path.iter()
+             .map(|point| point.0)
+             .max()
+             .unwrap()
+         )
+        .max()
+        .unwrap()
+        .max(500 + height);
+    let width = max_x - min_x + 1;
+    let mut field = vec![vec![Pixel::Air; height as usize]; width as usize];
+    for path in input {
+        for i in 1..path.len() {
+            let point0 = &path[i - 1];
+            let point1 = &path[i];
+            let dx = point1.0.cmp(&point0.0) as i32;
+            let dy = point1.1.cmp(&point0.1) as i32;
+            let mut point = point0.clone();
+            loop {
+                field[(point.0 - min_x) as usize][(point.1 - min_y) as usize] = Pixel::Stone;
+                if point == *point1 {
+                    break;
+                }
+                point.0 += dx;
+                point.1 += dy;
+            }
+        }
+    }
+    for column in field.iter_mut() {
+        column[height as usize - 1] = Pixel::Stone;
+    }
+    let sand_start = Point(500 - min_x, 0);
+    let mut sand_count = 0;
+    'outer: loop {
+        let mut sand = sand_start.clone();
+        while sand.0 >= 0 && sand.0 < width && sand.1 < height {
+            if field[sand.0 as usize][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+            } else if sand.0 == 0 ||
+                    field[sand.0 as usize - 1][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+                sand.0 -= 1;
+            } else if sand.0 == width - 1 ||
+                    field[sand.0 as usize + 1][sand.1 as usize + 1] == Pixel::Air {
+                sand.1 += 1;
+                sand.0 += 1;
+            } else {
+                field[sand.0 as usize][sand.1 as usize] = Pixel::Sand;
+                sand_count += 1;
+                if sand == sand_start {
+                    break 'outer;
+                } else {
+                    continue 'outer;
+                }
+            }
+        }
+        break;
+    }
+    Some(sand_count)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> IResult<&str, Input> {
+        input_parser(input)
+    }
+
+    fn part_one(input: &Input) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+crate::aoc_tests!(day = 14, parser = input_parser, part_one = Some(24), part_two = Some(93));
@@ -0,0 +1,62 @@
+/*
+ * Solutions, one module per day.
+ * A new day needs a `pub mod` line here and an entry in `ALL`, plus a binary in `src/bin`;
+ * `cargo scaffold` adds all three, keeping both lists sorted.
+ * Days of other years go in a module per year, e.g. `y2022/day07.rs` declared in `y2022/mod.rs`.
+ */
+use crate::day::DayId;
+use crate::solution::DynSolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+/// Every solved day, ordered by year and day.
+pub static ALL: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+];
+
+pub fn get(id: impl Into<DayId>) -> Option<&'static dyn DynSolution> {
+    let id = id.into();
+    ALL.iter().copied().find(|solution| solution.id() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered() {
+        let days: Vec<DayId> = ALL.iter().map(|solution| solution.id()).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(get(5).map(|solution| solution.id()), Some(DayId::from(5)));
+        assert!(get(0).is_none());
+        assert!(get((2022, 5)).is_none());
+    }
+}
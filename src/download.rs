@@ -0,0 +1,263 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// The Advent of Code website. Set `AOC_BASE_URL` or pass `--base-url` to download from
+/// somewhere else, e.g. a local server in tests.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The event of the days without a year: the solutions in `src/days` are for 2022.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Sent when `AOC_USER_AGENT` is not set. The Advent of Code website asks automated requests
+/// to name where they come from and whom to contact, which only the user of this crate knows.
+pub const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The file aoc-cli reads the session token from, in the home directory.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum DownloadError {
+    /// Neither `AOC_SESSION` nor the session file holds a token.
+    NoSession { file: Option<PathBuf> },
+    /// The server answered with an error status.
+    Status { url: String, status: u16, message: String },
+    /// The server could not be reached.
+    Transport { url: String, message: String },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::NoSession { file } => {
+                write!(f, "no session token: set AOC_SESSION")?;
+                match file {
+                    Some(file) => write!(f, " or put your session cookie in \"{}\"", file.display()),
+                    None => write!(f, " to your session cookie"),
+                }
+            }
+            DownloadError::Status { url, status, message } => {
+                write!(f, "{} answered with status {}", url, status)?;
+                if !message.is_empty() {
+                    write!(f, ": {}", message)?;
+                }
+                if *status == 400 || *status == 500 {
+                    write!(f, " (the session token may have expired)")?;
+                }
+                Ok(())
+            }
+            DownloadError::Transport { url, message } => write!(f, "could not reach {}: {}", url, message),
+            DownloadError::Io { path, error } => write!(f, "could not write {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DownloadError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The session token from `AOC_SESSION`, or else from `~/.adventofcode.session`, the file
+/// aoc-cli uses.
+pub fn session_token() -> Result<String, DownloadError> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+    let file = home.map(|home| Path::new(&home).join(SESSION_FILE));
+    find_session(env::var("AOC_SESSION").ok(), file)
+}
+
+fn find_session(from_env: Option<String>, file: Option<PathBuf>) -> Result<String, DownloadError> {
+    let from_file = file.as_ref().and_then(|file| fs::read_to_string(file).ok());
+    [from_env, from_file]
+        .into_iter()
+        .flatten()
+        .map(|token| token.trim().to_owned())
+        .find(|token| !token.is_empty())
+        .ok_or(DownloadError::NoSession { file })
+}
+
+/// The base URL from `AOC_BASE_URL`, or [`DEFAULT_BASE_URL`].
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_owned())
+}
+
+/// The user agent from `AOC_USER_AGENT`, e.g. `github.com/you/advent-of-code by you@example.com`,
+/// or `None` if it is not set.
+pub fn user_agent() -> Option<String> {
+    env::var("AOC_USER_AGENT").ok().filter(|agent| !agent.trim().is_empty())
+}
+
+pub fn input_url(base_url: &str, year: u16, day: u8) -> String {
+    format!("{}/{}/day/{}/input", base_url.trim_end_matches('/'), year, day)
+}
+
+/// Downloads the puzzle input of a day with the session token.
+pub fn fetch_input(
+    base_url: &str,
+    user_agent: &str,
+    session: &str,
+    year: u16,
+    day: u8,
+) -> Result<String, DownloadError> {
+    let url = input_url(base_url, year, day);
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent(user_agent)
+        .build();
+    let response = agent
+        .get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .call();
+    match response {
+        Ok(response) => response.into_string().map_err(|e| DownloadError::Transport {
+            url: url.clone(),
+            message: e.to_string(),
+        }),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(DownloadError::Status {
+                url,
+                status,
+                message: body.lines().next().unwrap_or_default().trim().to_owned(),
+            })
+        }
+        Err(ureq::Error::Transport(e)) => Err(DownloadError::Transport {
+            url,
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it to `path`, so `path`
+/// holds either its old contents or all of the new ones. Creates the parent folder if needed.
+pub fn write_atomically(path: &Path, contents: &str) -> Result<(), DownloadError> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let io_error = |error| DownloadError::Io {
+        path: path.to_owned(),
+        error,
+    };
+    let folder = match path.parent() {
+        Some(folder) if !folder.as_os_str().is_empty() => folder,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(folder).map_err(io_error)?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp = folder.join(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result.map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers one request with `status` and `body`, and returns the request line and headers.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request: Vec<String> = BufReader::new(stream.try_clone().unwrap())
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        assert_eq!(fetch_input(&base_url, "me@example.com", "abc", 2022, 1).unwrap(), "1000\n2000\n");
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=abc")));
+        assert!(request.iter().any(|line| line.eq_ignore_ascii_case("user-agent: me@example.com")));
+    }
+
+    #[test]
+    fn test_fetch_input_status() {
+        let (base_url, server) = serve_once("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!\n");
+        let error = fetch_input(&base_url, DEFAULT_USER_AGENT, "abc", 2022, 25).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(error, DownloadError::Status { status: 404, .. }));
+        assert!(error.to_string().ends_with("404: Please don't repeatedly request this endpoint before it unlocks!"));
+    }
+
+    #[test]
+    fn test_write_atomically() {
+        let folder = env::temp_dir().join(format!("aoc-download-{}", process::id()));
+        let path = folder.join("2022/01.txt");
+        write_atomically(&path, "old\n").unwrap();
+        write_atomically(&path, "new\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+        assert_eq!(fs::read_dir(folder.join("2022")).unwrap().count(), 1);
+        fs::remove_dir_all(folder).unwrap();
+    }
+
+    #[test]
+    fn test_find_session() {
+        let file = env::temp_dir().join(format!("aoc-session-{}", process::id()));
+        fs::write(&file, "from-file\n").unwrap();
+        assert_eq!(find_session(Some("from-env".into()), Some(file.clone())).unwrap(), "from-env");
+        assert_eq!(find_session(Some(" ".into()), Some(file.clone())).unwrap(), "from-file");
+        fs::remove_file(&file).unwrap();
+        assert!(matches!(
+            find_session(None, Some(file)),
+            Err(DownloadError::NoSession { file: Some(_) })
+        ));
+    }
+
+    #[test]
+    fn test_input_url() {
+        assert_eq!(input_url("http://localhost:8080/", 2022, 7), "http://localhost:8080/2022/day/7/input");
+    }
+}
@@ -0,0 +1,317 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::bench::BenchConfig;
+use crate::day::DayId;
+use crate::json::{Object, Value};
+use crate::report::PartResult;
+
+/// How `--record` and `--compare` time each part. A single run varies too much to compare,
+/// so the median of repeated runs is recorded instead.
+pub const BENCH_CONFIG: BenchConfig = BenchConfig {
+    max_runs: 1_000,
+    budget: Duration::from_secs(1),
+};
+
+/// Slowdowns smaller than this are noise, however large they are in percent.
+pub const MIN_SLOWDOWN: Duration = Duration::from_micros(1);
+
+/// Median timing of one part in one run of `cargo all -- --record`, stored as a line of
+/// `benchmarks/history.jsonl`:
+/// `{"commit":"4f2a9c1","date":"2026-10-18T09:30:00Z","profile":"release","day":8,"part":1,"median_ns":51230}`.
+/// Days of a year carry it as `year` before `day`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Short hash of `HEAD`, suffixed with `-dirty` if the tree had changes.
+    pub commit: String,
+    /// UTC time of the run in ISO 8601.
+    pub date: String,
+    /// `debug` or `release`; only runs with the same profile are compared.
+    pub profile: String,
+    pub day: DayId,
+    pub part: u8,
+    pub median: Duration,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        let object = Object::new()
+            .field("commit", Value::String(self.commit.clone()))
+            .field("date", Value::String(self.date.clone()))
+            .field("profile", Value::String(self.profile.clone()));
+        self.day
+            .to_fields(object)
+            .field("part", Value::Int(self.part.into()))
+            .field(
+                "median_ns",
+                Value::Int(self.median.as_nanos().try_into().unwrap_or(i64::MAX)),
+            )
+            .to_line()
+    }
+
+    pub fn from_json(line: &str) -> Option<Record> {
+        let object = Object::parse(line)?;
+        let string = |key| object.get(key).and_then(Value::as_str).map(str::to_owned);
+        Some(Record {
+            commit: string("commit")?,
+            date: string("date")?,
+            profile: string("profile")?,
+            day: DayId::from_fields(&object)?,
+            part: object.get("part")?.as_u64()?.try_into().ok()?,
+            median: Duration::from_nanos(object.get("median_ns")?.as_u64()?),
+        })
+    }
+}
+
+/// `benchmarks/history.jsonl` in the crate root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("benchmarks/history.jsonl")
+}
+
+/// The profile this crate was built with.
+pub fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Reads all records. A missing file is an empty history and lines that aren't records are skipped.
+pub fn load(path: &Path) -> io::Result<Vec<Record>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text.lines().filter_map(Record::from_json).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Appends `records` to the history, creating the file and its folder if needed.
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let lines: String = records.iter().map(|record| record.to_json() + "\n").collect();
+    file.write_all(lines.as_bytes())
+}
+
+/// The median of the part's timings with [`BENCH_CONFIG`], or its single run if it wasn't benchmarked.
+fn median(result: &PartResult) -> Duration {
+    result.bench.map_or(result.elapsed, |stats| stats.median)
+}
+
+/// Records for the solved parts in `results`, stamped with the current commit, time and profile.
+pub fn records(results: &[PartResult]) -> Vec<Record> {
+    let commit = current_commit();
+    let date = format_date(SystemTime::now());
+    results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .map(|result| Record {
+            commit: commit.clone(),
+            date: date.clone(),
+            profile: profile().to_owned(),
+            day: result.day,
+            part: result.part,
+            median: median(result),
+        })
+        .collect()
+}
+
+/// Short hash of `HEAD` with a `-dirty` suffix for uncommitted changes, or `unknown` outside of git.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty()) => {
+            format!("{}-dirty", hash)
+        }
+        Some(hash) => hash,
+        None => "unknown".into(),
+    }
+}
+
+/// Formats `time` as `YYYY-MM-DDTHH:MM:SSZ`.
+pub fn format_date(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()) as i64;
+    let (days, rest) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    // civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rest / 3600,
+        rest / 60 % 60,
+        rest % 60
+    )
+}
+
+/// Median timing of a part now and in the latest recorded run with the same profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: DayId,
+    pub part: u8,
+    pub baseline: Duration,
+    /// Commit of the baseline run.
+    pub commit: String,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change from the baseline, e.g. `0.25` for 25% slower.
+    pub fn change(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1_f64
+    }
+
+    /// Slower than the baseline by more than `threshold` percent and at least [`MIN_SLOWDOWN`].
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() * 100_f64 > threshold && self.current >= self.baseline + MIN_SLOWDOWN
+    }
+}
+
+/// Compares the solved parts in `results` with their latest records in `history`.
+/// Parts without a record for the current profile are left out.
+pub fn compare(history: &[Record], results: &[PartResult]) -> Vec<Comparison> {
+    let mut baseline: HashMap<(DayId, u8), &Record> = HashMap::new();
+    for record in history.iter().filter(|record| record.profile == profile()) {
+        baseline.insert((record.day, record.part), record);
+    }
+    results
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .filter_map(|result| {
+            let record = baseline.get(&(result.day, result.part))?;
+            Some(Comparison {
+                day: result.day,
+                part: result.part,
+                baseline: record.median,
+                commit: record.commit.clone(),
+                current: median(result),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::BenchStats;
+
+    fn record(day: u8, part: u8, micros: u64) -> Record {
+        Record {
+            commit: "4f2a9c1".into(),
+            date: "2026-10-18T09:30:00Z".into(),
+            profile: profile().into(),
+            day: day.into(),
+            part,
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_record_json_round_trip() {
+        let record = record(8, 1, 51);
+        assert_eq!(Record::from_json(&record.to_json()), Some(record.clone()));
+        let record = Record {
+            day: (2022, 8).into(),
+            ..record
+        };
+        assert_eq!(Record::from_json(&record.to_json()), Some(record));
+        assert_eq!(Record::from_json(r#"{"day":8,"part":1}"#), None);
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_date(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            "2023-11-14T22:13:20Z"
+        );
+        assert_eq!(
+            format_date(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_compare_uses_latest_record() {
+        let history = vec![record(8, 1, 100), record(8, 1, 40), record(14, 2, 10)];
+        let result = |day: u8, part, micros| PartResult {
+            day: day.into(),
+            part,
+            answer: Some("1".into()),
+            elapsed: Duration::from_micros(micros),
+            memory: None,
+            bench: None,
+            expected: None,
+            timed_out: false,
+        };
+        let comparisons = compare(&history, &[result(8, 1, 50), result(15, 1, 5)]);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline, Duration::from_micros(40));
+        assert!((comparisons[0].change() - 0.25).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10_f64));
+        assert!(!comparisons[0].is_regression(30_f64));
+    }
+
+    #[test]
+    fn test_compare_uses_median() {
+        let history = vec![record(8, 1, 40)];
+        let result = PartResult {
+            day: 8.into(),
+            part: 1,
+            answer: Some("1".into()),
+            elapsed: Duration::from_micros(90),
+            memory: None,
+            bench: Some(BenchStats {
+                runs: 3,
+                min: Duration::from_micros(40),
+                median: Duration::from_micros(42),
+                mean: Duration::from_micros(57),
+                p95: Duration::from_micros(90),
+                std_dev: Duration::from_micros(28),
+            }),
+            expected: None,
+            timed_out: false,
+        };
+        let comparisons = compare(&history, std::slice::from_ref(&result));
+        assert_eq!(comparisons[0].current, Duration::from_micros(42));
+        assert!(!comparisons[0].is_regression(10_f64));
+        assert_eq!(records(&[result])[0].median, Duration::from_micros(42));
+
+        let fast = Comparison {
+            day: 1.into(),
+            part: 1,
+            baseline: Duration::from_nanos(200),
+            commit: "4f2a9c1".into(),
+            current: Duration::from_nanos(400),
+        };
+        assert!(!fast.is_regression(10_f64));
+    }
+}
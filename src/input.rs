@@ -0,0 +1,340 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use nom::{
+    error::{Error, ErrorKind, VerboseError, VerboseErrorKind},
+    IResult, Offset,
+};
+
+use crate::args::InputSource;
+use crate::day::DayId;
+
+/// Position of a parse failure inside an input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Full text of the offending line, without its `\n`.
+    pub line_text: String,
+}
+
+#[derive(Debug)]
+pub enum InputErrorKind {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The parser failed. `kinds` lists the nom error kinds from the innermost parser outwards.
+    Syntax {
+        location: Location,
+        kinds: Vec<ErrorKind>,
+    },
+    /// The parser succeeded but did not consume the whole input.
+    TrailingInput { location: Location },
+    /// A streaming parser asked for more input.
+    Incomplete,
+}
+
+/// Error returned by [`try_read_file_nom`] and [`parse_input`].
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub kind: InputErrorKind,
+}
+
+/// Gives access to the failure position and the error kind chain of a nom error type.
+pub trait ErrorChain<'a> {
+    /// Returns the remaining input at the innermost failure and the error kinds,
+    /// innermost first.
+    fn chain(&self) -> (&'a str, Vec<ErrorKind>);
+}
+
+impl<'a> ErrorChain<'a> for Error<&'a str> {
+    fn chain(&self) -> (&'a str, Vec<ErrorKind>) {
+        (self.input, vec![self.code])
+    }
+}
+
+impl<'a> ErrorChain<'a> for VerboseError<&'a str> {
+    fn chain(&self) -> (&'a str, Vec<ErrorKind>) {
+        let position = self.errors.first().map_or("", |(input, _)| *input);
+        let kinds = self
+            .errors
+            .iter()
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Nom(kind) => Some(*kind),
+                VerboseErrorKind::Char(_) => Some(ErrorKind::Char),
+                VerboseErrorKind::Context(_) => None,
+            })
+            .collect();
+        (position, kinds)
+    }
+}
+
+impl Location {
+    /// Computes the location of `remaining`, which must be a suffix of `input`.
+    pub fn of(input: &str, remaining: &str) -> Location {
+        let offset = input.offset(remaining);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            line_text: input[line_start..line_end].to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.line_text.trim_end_matches('\r'))?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))?;
+        if self.line_text.ends_with('\r') {
+            write!(f, "\nnote: line ends with '\\r', the file probably has CRLF line endings")?;
+        } else if self.column > self.line_text.chars().count() {
+            write!(f, "\nnote: reached the end of the line, check for a missing trailing newline")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path.display();
+        match &self.kind {
+            InputErrorKind::Io(e) => write!(f, "could not open input file {}: {}", path, e),
+            InputErrorKind::Syntax { location, kinds } => {
+                let kinds: Vec<String> = kinds.iter().map(|kind| format!("{:?}", kind)).collect();
+                writeln!(
+                    f,
+                    "could not parse input file {}:{}:{}: {}",
+                    path,
+                    location.line,
+                    location.column,
+                    kinds.join(" <- ")
+                )?;
+                write!(f, "{}", location)
+            }
+            InputErrorKind::TrailingInput { location } => {
+                writeln!(
+                    f,
+                    "input file {}:{}:{} wasn't fully parsed",
+                    path, location.line, location.column
+                )?;
+                write!(f, "{}", location)
+            }
+            InputErrorKind::Incomplete => {
+                write!(f, "could not parse input file {}: unexpected end of input", path)
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            InputErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Path of `src/<folder>/NN.txt` inside this crate, independent of the working directory.
+/// `day` is a day number, or `(year, day)` for a day of a year, see [`DayId`].
+pub fn input_path(folder: &str, day: impl Into<DayId>) -> PathBuf {
+    day.into().path(folder)
+}
+
+pub fn read_file(folder: &str, day: impl Into<DayId>) -> String {
+    let f = fs::read_to_string(input_path(folder, day));
+    f.expect("could not open input file")
+}
+
+/// Reads the named example `src/examples/NN-<name>.txt`, for days with several examples.
+pub fn read_example(day: impl Into<DayId>, name: &str) -> String {
+    let f = fs::read_to_string(day.into().named_path("examples", name));
+    f.expect("could not open example file")
+}
+
+/// Runs `parser` over `input` and requires it to consume everything.
+/// `path` is only used to describe the input in errors.
+pub fn parse_input<'a, O, E, F>(path: &Path, input: &'a str, mut parser: F) -> Result<O, InputError>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O, E>,
+    E: ErrorChain<'a>,
+{
+    let error = |kind| InputError {
+        path: path.to_owned(),
+        kind,
+    };
+    match parser(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(error(InputErrorKind::TrailingInput {
+            location: Location::of(input, rest),
+        })),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            let (remaining, kinds) = e.chain();
+            Err(error(InputErrorKind::Syntax {
+                location: Location::of(input, remaining),
+                kinds,
+            }))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(error(InputErrorKind::Incomplete)),
+    }
+}
+
+pub fn try_read_file_nom<F, O>(folder: &str, day: impl Into<DayId>, parser: F) -> Result<O, InputError>
+where
+    F: for<'input> Fn(&'input str) -> IResult<&'input str, O, Error<&'input str>>,
+{
+    let path = input_path(folder, day);
+    let input_string = fs::read_to_string(&path).map_err(|e| InputError {
+        path: path.clone(),
+        kind: InputErrorKind::Io(e),
+    })?;
+    parse_input(&path, &input_string, parser)
+}
+
+pub fn read_file_nom<F, O>(folder: &str, day: impl Into<DayId>, parser: F) -> O
+where
+    F: for<'input> Fn(&'input str) -> IResult<&'input str, O, Error<&'input str>>,
+{
+    try_read_file_nom(folder, day, parser).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Like [`try_read_file_nom`] for the named example `src/examples/NN-<name>.txt`.
+pub fn try_read_example_nom<F, O>(day: impl Into<DayId>, name: &str, parser: F) -> Result<O, InputError>
+where
+    F: for<'input> Fn(&'input str) -> IResult<&'input str, O, Error<&'input str>>,
+{
+    let (path, input_string) = read_source(&InputSource::NamedExample(name.to_owned()), day.into())?;
+    parse_input(&path, &input_string, parser)
+}
+
+/// Like [`read_file_nom`] for the named example `src/examples/NN-<name>.txt`:
+///
+/// ```ignore
+/// let input = crate::read_example_nom(9, "larger", input_parser);
+/// ```
+pub fn read_example_nom<F, O>(day: impl Into<DayId>, name: &str, parser: F) -> O
+where
+    F: for<'input> Fn(&'input str) -> IResult<&'input str, O, Error<&'input str>>,
+{
+    try_read_example_nom(day, name, parser).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads the input selected by `source`, returning it with the path used in messages.
+pub fn read_source(source: &InputSource, day: DayId) -> Result<(PathBuf, String), InputError> {
+    let path = match source {
+        InputSource::Inputs => day.path("inputs"),
+        InputSource::Examples => day.path("examples"),
+        InputSource::NamedExample(name) => day.named_path("examples", name),
+        InputSource::Path(path) => path.clone(),
+        InputSource::Stdin => PathBuf::from("<stdin>"),
+    };
+    let contents = match source {
+        InputSource::Stdin => io::read_to_string(io::stdin()),
+        _ => fs::read_to_string(&path),
+    };
+    match contents {
+        Ok(contents) => Ok((path, contents)),
+        Err(e) => Err(InputError {
+            path,
+            kind: InputErrorKind::Io(e),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nom::{
+        character::complete::{char, digit1},
+        multi::many1,
+        sequence::{separated_pair, terminated},
+    };
+
+    fn lines_parser(input: &str) -> IResult<&str, Vec<&str>> {
+        many1(terminated(digit1, char('\n')))(input)
+    }
+
+    fn parse(input: &str) -> Result<Vec<&str>, InputError> {
+        parse_input(Path::new("test.txt"), input, lines_parser)
+    }
+
+    #[test]
+    fn test_parse_input_ok() {
+        assert_eq!(parse("1\n22\n").unwrap(), vec!["1", "22"]);
+    }
+
+    #[test]
+    fn test_parse_input_crlf() {
+        let e = parse("1\n22\r\n3\n").unwrap_err();
+        match &e.kind {
+            InputErrorKind::TrailingInput { location } => {
+                assert_eq!((location.line, location.column), (2, 1));
+                assert_eq!(location.line_text, "22\r");
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+        assert!(e.to_string().contains("CRLF"));
+    }
+
+    #[test]
+    fn test_parse_input_missing_newline() {
+        fn pair_parser(input: &str) -> IResult<&str, (&str, &str)> {
+            terminated(separated_pair(digit1, char(' '), digit1), char('\n'))(input)
+        }
+        let e = parse_input(Path::new("test.txt"), "12 34", pair_parser).unwrap_err();
+        match &e.kind {
+            InputErrorKind::Syntax { location, kinds } => {
+                assert_eq!((location.line, location.column), (1, 6));
+                assert_eq!(kinds, &vec![ErrorKind::Char]);
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+        assert!(e.to_string().contains("missing trailing newline"));
+    }
+
+    #[test]
+    fn test_parse_input_trailing() {
+        let e = parse("1\n22\nx\n").unwrap_err();
+        match &e.kind {
+            InputErrorKind::TrailingInput { location } => {
+                assert_eq!((location.line, location.column), (3, 1));
+                assert_eq!(location.line_text, "x");
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+        assert_eq!(
+            e.to_string(),
+            "input file test.txt:3:1 wasn't fully parsed\n  |\n3 | x\n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_verbose_chain() {
+        fn verbose_parser(input: &str) -> IResult<&str, Vec<&str>, VerboseError<&str>> {
+            many1(terminated(digit1, char('\n')))(input)
+        }
+        let e = parse_input(Path::new("test.txt"), "x\n", verbose_parser).unwrap_err();
+        match &e.kind {
+            InputErrorKind::Syntax { location, kinds } => {
+                assert_eq!((location.line, location.column), (1, 1));
+                assert_eq!(kinds, &vec![ErrorKind::Digit, ErrorKind::Many1]);
+            }
+            kind => panic!("unexpected error kind {:?}", kind),
+        }
+    }
+}
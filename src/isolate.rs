@@ -0,0 +1,284 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::args::InputSource;
+use crate::day::DayId;
+use crate::runner::{DayError, DayReport, RunOptions};
+
+/// Builds the binary of `day` in the same profile as the current process, runs it with
+/// `--json` and turns its output into a report. Build failures, panics and failure statuses
+/// become a [`DayError`] carrying what the binary printed to stderr.
+///
+/// With a timeout, each part runs in a binary of its own, so a part that times out ends with
+/// its process instead of slowing down the next one. A binary that is still running long
+/// after the timeout, e.g. because parsing hangs, is killed.
+pub fn run_day(day: DayId, source: &InputSource, options: &RunOptions) -> Result<DayReport, DayError> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let bin = day.bin_name();
+    if !root.join("src/bin").join(format!("{}.rs", bin)).exists() {
+        return Err(DayError::NotPresent);
+    }
+    build(root, &bin)?;
+    let binary = binary_path(&bin);
+
+    if options.timeout.is_none() {
+        return run_binary(root, &binary, source, options, options.part);
+    }
+    let mut report: Option<DayReport> = None;
+    for part in (1..=2).filter(|&part| options.part.is_none_or(|only| only == part)) {
+        let result = run_binary(root, &binary, source, options, Some(part))?;
+        match &mut report {
+            Some(report) => report.parts.extend(result.parts),
+            None => report = Some(result),
+        }
+    }
+    Ok(report.expect("--part selects part 1 or 2"))
+}
+
+/// Builds the binary `bin` with `cargo build`.
+fn build(root: &Path, bin: &str) -> Result<(), DayError> {
+    let mut cmd = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cmd.current_dir(root)
+        .args(["build", "--quiet", "--bin", bin]);
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    if cfg!(feature = "memory") {
+        cmd.args(["--features", "memory"]);
+    }
+    let output = cmd
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| DayError::Exited {
+            code: None,
+            stderr: format!("could not run cargo: {}", e),
+        })?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(failure(output.status.code(), &String::from_utf8_lossy(&output.stderr)))
+    }
+}
+
+/// Cargo puts the day binaries next to the current one, in the folder of its profile.
+/// Test binaries are one level deeper, in `deps`.
+fn binary_path(bin: &str) -> PathBuf {
+    let current = env::current_exe().unwrap_or_default();
+    let mut folder = current.parent().unwrap_or(Path::new("."));
+    if folder.ends_with("deps") {
+        folder = folder.parent().unwrap_or(folder);
+    }
+    folder.join(format!("{}{}", bin, env::consts::EXE_SUFFIX))
+}
+
+/// Runs a day binary on `source`, for one part or both. With a timeout, the binary gives up on
+/// the part after the timeout and is killed if it hasn't finished after about twice that.
+fn run_binary(
+    root: &Path,
+    binary: &Path,
+    source: &InputSource,
+    options: &RunOptions,
+    part: Option<u8>,
+) -> Result<DayReport, DayError> {
+    let mut cmd = Command::new(binary);
+    cmd.current_dir(root).arg("--json");
+    match source {
+        InputSource::Inputs => {}
+        InputSource::Examples => {
+            cmd.arg("--example");
+        }
+        InputSource::NamedExample(name) => {
+            cmd.args(["--example-name", name]);
+        }
+        InputSource::Path(path) => {
+            cmd.arg("--input").arg(path);
+        }
+        InputSource::Stdin => {
+            cmd.arg("-");
+        }
+    }
+    if let Some(part) = part {
+        cmd.args(["--part", &part.to_string()]);
+    }
+    if let Some(config) = options.bench {
+        cmd.args(["--bench-runs", &config.max_runs.to_string()]);
+        cmd.args(["--bench-time", &config.budget.as_secs_f64().to_string()]);
+    }
+    if let Some(limit) = options.timeout {
+        cmd.args(["--timeout", &limit.as_secs_f64().to_string()]);
+    }
+
+    let could_not_run = |e: io::Error| DayError::Exited {
+        code: None,
+        stderr: format!("could not run {}: {}", binary.display(), e),
+    };
+    let output = match options.timeout {
+        None => cmd.stdin(Stdio::null()).output().map_err(could_not_run)?,
+        // the part gets `limit`, starting up, reading and parsing get the same again.
+        Some(limit) => {
+            let deadline = limit + limit.max(Duration::from_secs(1));
+            output_within(&mut cmd, deadline)
+                .map_err(could_not_run)?
+                .ok_or(DayError::TimedOut { limit: deadline })?
+        }
+    };
+    classify(
+        output.status.code(),
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+        part,
+    )
+}
+
+/// Runs `cmd` like [`Command::output`], but kills it if it runs longer than `limit`.
+/// Returns `None` if it was killed.
+fn output_within(cmd: &mut Command, limit: Duration) -> io::Result<Option<Output>> {
+    let start = Instant::now();
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // read both pipes while waiting, so a chatty child doesn't block on a full pipe.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if start.elapsed() >= limit {
+            kill(&mut child)?;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(5));
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok(status.map(|status| Output { status, stdout, stderr }))
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
+
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()?;
+    child.wait().map(|_| ())
+}
+
+/// Turns the status and output of a day binary run for `part`, or both parts, into a report.
+/// A binary that printed its results counts as run, even if it exited with a failure status
+/// for a wrong answer.
+fn classify(
+    code: Option<i32>,
+    stdout: &str,
+    stderr: &str,
+    part: Option<u8>,
+) -> Result<DayReport, DayError> {
+    DayReport::from_output(stdout, part).ok_or_else(|| failure(code, stderr))
+}
+
+/// Tells why a day binary or its build failed, from its status and stderr.
+fn failure(code: Option<i32>, stderr: &str) -> DayError {
+    if stderr.contains("error: could not compile") {
+        DayError::BuildFailed {
+            stderr: stderr.to_owned(),
+        }
+    } else if let Some(message) = panic_message(stderr) {
+        DayError::Panicked { message }
+    } else {
+        DayError::Exited {
+            code,
+            stderr: stderr.to_owned(),
+        }
+    }
+}
+
+/// Finds the message of the default panic hook in `stderr`:
+///
+/// ```text
+/// thread 'main' panicked at src/days/day07.rs:12:5:
+/// no directory named "a"
+/// note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+/// ```
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"));
+    let location = lines.next()?;
+    let message: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("note: ") && *line != "stack backtrace:")
+        .collect();
+    let location = location.split_once("panicked at ")?.1.trim_end_matches(':');
+    Some(format!("{} ({})", message.join("\n"), location))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STDOUT: &str = r#"{"day":7,"stage":"input","read_ns":15200,"parse_ns":120430,"path":"src/inputs/07.txt","bytes":10,"lines":2}
+{"day":7,"part":1,"answer":"95437","elapsed_ns":1234,"status":"solved"}
+{"day":7,"part":2,"answer":"1","elapsed_ns":4321,"status":"wrong","expected":"2"}
+"#;
+
+    #[test]
+    fn test_classify_solved() {
+        let report = classify(Some(1), STDOUT, "", None).unwrap();
+        assert_eq!(report.input.day, 7.into());
+        assert!(report.has_wrong_answer());
+        let first_part = STDOUT.lines().take(2).collect::<Vec<_>>().join("\n");
+        let report = classify(Some(0), &first_part, "", Some(1)).unwrap();
+        assert_eq!(report.parts.len(), 1);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_output_within() {
+        let output = output_within(Command::new("echo").arg("done"), Duration::from_secs(5)).unwrap();
+        assert_eq!(output.unwrap().stdout, b"done\n");
+        let start = Instant::now();
+        assert!(output_within(Command::new("sleep").arg("10"), Duration::from_millis(50)).unwrap().is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_classify_failures() {
+        let stderr = "error[E0425]: cannot find value `x` in this scope\nerror: could not compile `advent_of_code` (lib) due to 1 previous error\n";
+        assert!(matches!(
+            classify(Some(101), "", stderr, None),
+            Err(DayError::BuildFailed { .. })
+        ));
+
+        let stderr = "thread 'main' panicked at src/days/day07.rs:12:5:\nno directory named \"a\"\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+        let first_part = STDOUT.lines().take(2).collect::<Vec<_>>().join("\n");
+        match classify(Some(101), &first_part, stderr, None) {
+            Err(DayError::Panicked { message }) => {
+                assert_eq!(message, "no directory named \"a\" (src/days/day07.rs:12:5)")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        match classify(Some(3), "", "could not read src/inputs/07.txt\n", None) {
+            Err(DayError::Exited {
+                code: Some(3),
+                stderr,
+            }) => assert!(stderr.contains("07.txt")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
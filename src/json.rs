@@ -0,0 +1,218 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * Minimal reading and writing of flat JSON objects, one per line, with only what the
+ * machine-readable output needs: string, number, bool and null values.
+ */
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Int(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// A flat JSON object with fields in insertion order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Object {
+    fields: Vec<(String, Value)>,
+}
+
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    pub fn field(mut self, key: &str, value: Value) -> Object {
+        self.fields.push((key.to_owned(), value));
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn to_line(&self) -> String {
+        let mut line = String::from("{");
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            write_string(&mut line, key);
+            line.push(':');
+            match value {
+                Value::Null => line.push_str("null"),
+                Value::Bool(b) => write!(line, "{}", b).unwrap(),
+                Value::Int(n) => write!(line, "{}", n).unwrap(),
+                // JSON has no infinity or NaN.
+                Value::Float(n) if !n.is_finite() => line.push_str("null"),
+                Value::Float(n) => write!(line, "{:?}", n).unwrap(),
+                Value::String(s) => write_string(&mut line, s),
+            }
+        }
+        line.push('}');
+        line
+    }
+
+    /// Parses a single-line JSON object. Nested arrays and objects are not supported.
+    pub fn parse(line: &str) -> Option<Object> {
+        let mut chars = line.trim().chars().peekable();
+        let mut object = Object::new();
+        expect(&mut chars, '{')?;
+        skip_whitespace(&mut chars);
+        if chars.peek() == Some(&'}') {
+            chars.next();
+        } else {
+            loop {
+                skip_whitespace(&mut chars);
+                let key = parse_string(&mut chars)?;
+                skip_whitespace(&mut chars);
+                expect(&mut chars, ':')?;
+                skip_whitespace(&mut chars);
+                let value = parse_value(&mut chars)?;
+                object.fields.push((key, value));
+                skip_whitespace(&mut chars);
+                match chars.next()? {
+                    ',' => continue,
+                    '}' => break,
+                    _ => return None,
+                }
+            }
+        }
+        match chars.next() {
+            None => Some(object),
+            Some(_) => None,
+        }
+    }
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn skip_whitespace(chars: &mut Chars) {
+    while chars.peek().is_some_and(|c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut Chars, expected: char) -> Option<()> {
+    (chars.next()? == expected).then_some(())
+}
+
+fn parse_string(chars: &mut Chars) -> Option<String> {
+    expect(chars, '"')?;
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                'b' => s.push('\u{8}'),
+                'f' => s.push('\u{c}'),
+                'u' => {
+                    let code: String = (0..4).filter_map(|_| chars.next()).collect();
+                    s.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => s.push(c),
+            },
+            c => s.push(c),
+        }
+    }
+}
+
+fn parse_value(chars: &mut Chars) -> Option<Value> {
+    match chars.peek()? {
+        '"' => parse_string(chars).map(Value::String),
+        _ => {
+            let mut literal = String::new();
+            while let Some(&c) = chars.peek() {
+                if c == ',' || c == '}' || c.is_whitespace() {
+                    break;
+                }
+                literal.push(c);
+                chars.next();
+            }
+            match literal.as_str() {
+                "null" => Some(Value::Null),
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                number => match number.parse() {
+                    Ok(n) => Some(Value::Int(n)),
+                    Err(_) => number.parse().ok().map(Value::Float),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let object = Object::new()
+            .field("day", Value::Int(10))
+            .field("ratio", Value::Float(0.5))
+            .field("answer", Value::String("#..#\n\"x\"\\".into()))
+            .field("missing", Value::Null)
+            .field("ok", Value::Bool(true));
+        let line = object.to_line();
+        assert_eq!(
+            line,
+            r##"{"day":10,"ratio":0.5,"answer":"#..#\n\"x\"\\","missing":null,"ok":true}"##
+        );
+        assert_eq!(Object::parse(&line), Some(object));
+
+        let object = Object::new()
+            .field("a", Value::Float(f64::INFINITY))
+            .field("b", Value::Float(f64::NAN));
+        assert_eq!(object.to_line(), r#"{"a":null,"b":null}"#);
+    }
+
+    #[test]
+    fn test_parse() {
+        let object = Object::parse(r#" { "a" : 1.5 , "b":"é" } "#).unwrap();
+        assert_eq!(object.get("a"), Some(&Value::Float(1.5)));
+        assert_eq!(object.get("b").and_then(Value::as_str), Some("é"));
+        assert_eq!(Object::parse("{}"), Some(Object::new()));
+        assert_eq!(Object::parse("🎄 Part 1 🎄"), None);
+        assert_eq!(Object::parse(r#"{"a":1} trailing"#), None);
+    }
+}
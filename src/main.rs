@@ -2,45 +2,363 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::args::{AllArgs, InputSource, ReportFormat, Verbosity};
+use advent_of_code::history;
+use advent_of_code::report::{OutputMode, PartResult, PartStatus};
+use advent_of_code::runner::{run_days, DayError, DayReport, RunOptions, EXIT_FAILED, EXIT_WRONG_ANSWER};
+use advent_of_code::style::{bold, italic, reset};
+use advent_of_code::summary::{summary_table, update_readme};
+use advent_of_code::table::Table;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct Totals {
+    read: Duration,
+    parse: Duration,
+    solve: Duration,
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = AllArgs::get();
+    let options = RunOptions {
+        // the history holds medians, see `history::BENCH_CONFIG`.
+        bench: (args.record || args.compare).then_some(history::BENCH_CONFIG),
+        timeout: args.timeout,
+        part: args.part,
+        isolate: args.isolate,
+    };
+    if args.examples {
+        run_examples(&args, options);
+    } else {
+        run_inputs(&args, options);
+    }
+}
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+/// Solves every selected day on its real input and prints the results with a total,
+/// either day by day or as a summary table. With `--quiet`, only the answers are printed.
+fn run_inputs(args: &AllArgs, options: RunOptions) {
+    let pretty = args.format == ReportFormat::Pretty;
+    let mode = OutputMode::from(args.verbosity);
+    let quiet = mode == OutputMode::Quiet;
+    let wall_clock = Instant::now();
+    let reports = run_days(args.days.clone(), InputSource::Inputs, options, args.jobs);
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let mut totals = Totals::default();
+    let mut wrong_answers = 0;
+    let mut timed_out_parts = 0;
+    let mut failed_days = 0;
+    let mut results = Vec::new();
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    for (day, result) in reports {
+        let present = !matches!(result, Err(DayError::NotPresent));
+        if (args.only_solved || !pretty || quiet) && !present {
+            continue;
+        }
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
+        if pretty && !quiet {
+            let banner = format!("| Day {} |", day);
+            println!("{}", "-".repeat(banner.len()));
+            println!("{}{}{}", bold(), banner, reset());
+            println!("{}", "-".repeat(banner.len()));
+        }
+
+        match &result {
+            Ok(report) => {
+                if pretty {
+                    report.print(mode);
+                }
+                totals.read += report.input.read;
+                totals.parse += report.input.parse;
+                for part in &report.parts {
+                    match part.status() {
+                        // `elapsed` is only the limit, so it stays out of the totals.
+                        PartStatus::TimedOut => {
+                            timed_out_parts += 1;
+                            if !pretty || quiet {
+                                eprintln!("Day {} part {}: timed out after {:.2?}", day, part.part, part.elapsed);
+                            }
+                            continue;
+                        }
+                        PartStatus::Wrong => wrong_answers += 1,
+                        PartStatus::Correct | PartStatus::Solved | PartStatus::Unsolved => {}
+                    }
+                    totals.solve += part.elapsed;
+                }
+            }
+            Err(DayError::NotPresent) => println!("Not solved."),
+            Err(e) => {
+                failed_days += 1;
+                if pretty && !quiet {
+                    println!("{}", e);
                 } else {
-                    output.trim()
+                    eprintln!("Day {}: {}", day, e);
                 }
+            }
+        }
+        if present {
+            results.push((day, result));
+        }
+    }
+
+    let total = totals.read + totals.parse + totals.solve;
+    let table = summary_table(&results);
+    match args.format {
+        ReportFormat::Pretty => {}
+        ReportFormat::Table => print!("{}", table.render()),
+        ReportFormat::Markdown => {
+            print!("{}", table.render_markdown());
+            println!();
+            println!("**Total: {:.2}ms**", total.as_secs_f64() * 1000_f64);
+        }
+    }
+    if args.format != ReportFormat::Markdown && !quiet {
+        println!(
+            "{}Total:{} {}{:.2}ms (read: {:.2?}, parse: {:.2?}, solve: {:.2?}){}",
+            bold(),
+            reset(),
+            italic(),
+            total.as_secs_f64() * 1000_f64,
+            totals.read,
+            totals.parse,
+            totals.solve,
+            reset()
+        );
+        if args.jobs > 1 {
+            println!(
+                "{}Wall clock:{} {}{:.2?} with {} jobs{}",
+                bold(),
+                reset(),
+                italic(),
+                wall_clock.elapsed(),
+                args.jobs,
+                reset()
             );
+        }
+    }
+
+    if args.update_readme {
+        let section = format!(
+            "{}\n**Total: {:.2}ms**\n",
+            table.render_markdown(),
+            total.as_secs_f64() * 1000_f64
+        );
+        let readme = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+        match update_readme(&readme, &section) {
+            Ok(()) => eprintln!("Updated the runtimes in {}.", readme.display()),
+            Err(e) => {
+                eprintln!("could not update README.md: {}", e);
+                process::exit(EXIT_FAILED);
+            }
+        }
+    }
+
+    let parts: Vec<PartResult> = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .flat_map(|report| report.parts.iter().cloned())
+        .collect();
+    let regressions = if args.record || args.compare {
+        track_history(args, &parts)
+    } else {
+        0
+    };
+
+    if wrong_answers > 0 {
+        eprintln!("{} answer(s) differ from src/answers.", wrong_answers);
+    }
+    if timed_out_parts > 0 {
+        eprintln!("{} part(s) timed out.", timed_out_parts);
+        if !args.isolate {
+            eprintln!("Timed-out parts keep running in the background, so the timings after them are unreliable. Pass --isolate to end them with their process.");
+        }
+    }
+    if failed_days > 0 {
+        eprintln!("{} day(s) could not be run.", failed_days);
+    }
+    if timed_out_parts > 0 || failed_days > 0 {
+        process::exit(EXIT_FAILED);
+    }
+    if wrong_answers > 0 || regressions > 0 {
+        process::exit(EXIT_WRONG_ANSWER);
+    }
+}
+
+/// Compares the timings of `parts` with the history and/or appends them to it.
+/// Returns the number of parts that got slower than allowed by `--threshold`.
+fn track_history(args: &AllArgs, parts: &[PartResult]) -> usize {
+    let path = args.history.clone().unwrap_or_else(history::default_path);
+    let mut regressions = 0;
+
+    if args.compare {
+        let records = history::load(&path).unwrap_or_else(|e| {
+            eprintln!("could not read {}: {}", path.display(), e);
+            process::exit(EXIT_FAILED);
+        });
+        let comparisons = history::compare(&records, parts);
+        let mut table = Table::new(&["Day", "Part", "Baseline", "Commit", "Now", "Change"]);
+        for comparison in &comparisons {
+            let regressed = comparison.is_regression(args.threshold);
+            regressions += usize::from(regressed);
+            table.push(vec![
+                comparison.day.to_string(),
+                comparison.part.to_string(),
+                format!("{:.2?}", comparison.baseline),
+                comparison.commit.clone(),
+                format!("{:.2?}", comparison.current),
+                format!(
+                    "{:+.1}%{}",
+                    comparison.change() * 100_f64,
+                    if regressed { " ✘" } else { "" }
+                ),
+            ]);
+        }
+        println!();
+        if comparisons.is_empty() {
+            println!("No {} timings recorded in {} yet.", history::profile(), path.display());
+        } else {
+            print!("{}", table.render());
+            println!(
+                "{}{} of {} part(s) got more than {}% slower.{}",
+                bold(),
+                regressions,
+                comparisons.len(),
+                args.threshold,
+                reset()
+            );
+        }
+    }
+
+    if args.record {
+        let records = history::records(parts);
+        if let Err(e) = history::append(&path, &records) {
+            eprintln!("could not write {}: {}", path.display(), e);
+            process::exit(EXIT_FAILED);
+        }
+        eprintln!("Recorded {} timing(s) in {}.", records.len(), path.display());
+    }
+
+    regressions
+}
+
+/// Tally of the example checks of [`run_examples`], one table row per example.
+struct ExampleChecks {
+    table: Table,
+    failures: Vec<String>,
+    passed: usize,
+    failed: usize,
+    unchecked: usize,
+    timed_out: usize,
+    failed_days: usize,
+}
+
+impl ExampleChecks {
+    /// Adds the row of one example, labeled e.g. `09` or `09 larger` for a named example.
+    fn add(&mut self, label: String, report: Result<DayReport, DayError>) {
+        let mut row = vec![label.clone(), "-".into(), "-".into()];
+        match report {
+            Ok(report) => {
+                for part in &report.parts {
+                    row[part.part as usize] = match part.status() {
+                        PartStatus::Correct => {
+                            self.passed += 1;
+                            "pass".into()
+                        }
+                        PartStatus::Wrong => {
+                            self.failed += 1;
+                            self.failures.push(format!(
+                                "Day {} part {}: expected {:?}, got {}",
+                                label,
+                                part.part,
+                                part.expected.as_deref().unwrap_or_default(),
+                                part.answer.as_ref().map_or("no answer".into(), |answer| format!("{:?}", answer))
+                            ));
+                            "FAIL".into()
+                        }
+                        PartStatus::TimedOut => {
+                            self.timed_out += 1;
+                            self.failures.push(format!(
+                                "Day {} part {}: timed out after {:.2?}",
+                                label, part.part, part.elapsed
+                            ));
+                            "timed out".into()
+                        }
+                        PartStatus::Solved => {
+                            self.unchecked += 1;
+                            "no expected answer".into()
+                        }
+                        PartStatus::Unsolved => "not solved".into(),
+                    };
+                }
+            }
+            Err(e) => {
+                self.failed_days += 1;
+                row[1] = e.kind().into();
+                row[2] = e.kind().into();
+                self.failures.push(format!("Day {}: {}", label, e));
+            }
+        }
+        self.table.push(row);
+    }
+}
+
+/// Solves every selected day that has a solution on its examples and prints a pass/fail
+/// table, comparing with `src/examples/answers`. Named examples (`src/examples/NN-<name>.txt`)
+/// get a row each after the day's example. Exits with the same statuses as
+/// `run_inputs` if anything failed.
+/// With `--quiet`, only the failures are printed.
+fn run_examples(args: &AllArgs, options: RunOptions) {
+    let reports = run_days(args.days.clone(), InputSource::Examples, options.clone(), args.jobs);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let mut checks = ExampleChecks {
+        table: Table::new(&["Day", "Part 1", "Part 2"]),
+        failures: Vec::new(),
+        passed: 0,
+        failed: 0,
+        unchecked: 0,
+        timed_out: 0,
+        failed_days: 0,
+    };
+    for (day, report) in reports {
+        if let Err(DayError::NotPresent) = report {
+            continue;
+        }
+        checks.add(day.to_string(), report);
+        for name in day.names_in("examples") {
+            let source = InputSource::NamedExample(name.clone());
+            for (_, report) in run_days(vec![day], source, options.clone(), 1) {
+                checks.add(format!("{} {}", day, name), report);
             }
-        })
-        .sum();
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if args.verbosity == Verbosity::Quiet {
+        for failure in &checks.failures {
+            println!("{}", failure);
+        }
+    } else {
+        print!("{}", checks.table.render());
+        for failure in &checks.failures {
+            println!();
+            println!("{}", failure);
+        }
+        println!();
+        println!(
+            "{}{} passed, {} failed, {} without expected answer, {} timed out, {} example(s) could not be run{}",
+            bold(),
+            checks.passed,
+            checks.failed,
+            checks.unchecked,
+            checks.timed_out,
+            checks.failed_days,
+            reset()
+        );
+    }
+    if checks.timed_out > 0 || checks.failed_days > 0 {
+        process::exit(EXIT_FAILED);
+    }
+    if checks.failed > 0 {
+        process::exit(EXIT_WRONG_ANSWER);
+    }
 }
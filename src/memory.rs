@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+/// Heap usage of one part, recorded when the crate is built with `--features memory`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Number of allocations, counting each reallocation as one.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub allocated: u64,
+    /// Highest heap usage reached while the part ran, above what was in use when it started.
+    pub peak: u64,
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocations: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.allocated),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `512 B` or `1.50 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    pub static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    pub static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+    pub static IN_USE: AtomicU64 = AtomicU64::new(0);
+    pub static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// Forwards to the system allocator and keeps count of what passes through.
+    /// The counters are process-wide, so allocations of other threads are included.
+    struct Counting;
+
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let in_use = IN_USE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(in_use, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+/// Runs `func` and records its heap usage. Returns `None` for the statistics
+/// unless the crate is built with `--features memory`.
+#[cfg(feature = "memory")]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    use counting::{ALLOCATED, ALLOCATIONS, IN_USE, PEAK};
+    use std::sync::atomic::Ordering;
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let base = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let result = func();
+    let stats = MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+    };
+    (result, Some(stats))
+}
+
+/// Runs `func` and records its heap usage. Returns `None` for the statistics
+/// unless the crate is built with `--features memory`.
+#[cfg(not(feature = "memory"))]
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    (func(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MiB");
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn test_measure_counts_allocations() {
+        let (len, stats) = measure(|| std::hint::black_box(vec![0_u8; 4096]).len());
+        assert_eq!(len, 4096);
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 1);
+        assert!(stats.allocated >= 4096);
+    }
+
+    #[cfg(not(feature = "memory"))]
+    #[test]
+    fn test_measure_without_feature() {
+        assert_eq!(measure(|| 42), (42, None));
+    }
+}
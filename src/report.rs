@@ -0,0 +1,478 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::answers;
+use crate::args::{DayArgs, Verbosity};
+use crate::bench::BenchStats;
+use crate::day::DayId;
+use crate::json::{Object, Value};
+use crate::memory::{self, MemoryStats};
+use crate::style::{bold, italic, reset};
+
+/// How the results of a day are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Banners and timings for humans.
+    Pretty,
+    /// Only the answers, one per line, selected with `--quiet`.
+    Quiet,
+    /// Banners and exact timings with the input path and size, selected with `--verbose`.
+    Verbose,
+    /// One JSON object per part, selected with `--json` or `AOC_OUTPUT=json`.
+    Json,
+}
+
+impl OutputMode {
+    pub fn get() -> OutputMode {
+        let env_json = env::var("AOC_OUTPUT").is_ok_and(|v| v.eq_ignore_ascii_case("json"));
+        let args = DayArgs::get();
+        if args.json || env_json {
+            OutputMode::Json
+        } else {
+            OutputMode::from(args.verbosity)
+        }
+    }
+}
+
+impl From<Verbosity> for OutputMode {
+    fn from(verbosity: Verbosity) -> OutputMode {
+        match verbosity {
+            Verbosity::Quiet => OutputMode::Quiet,
+            Verbosity::Normal => OutputMode::Pretty,
+            Verbosity::Verbose => OutputMode::Verbose,
+        }
+    }
+}
+
+/// How a part turned out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The answer matches the accepted one.
+    Correct,
+    /// The answer differs from the accepted one, or the solver returned `None` despite one.
+    Wrong,
+    /// There is no accepted answer to compare with.
+    Solved,
+    /// The solver returned `None` and there is no accepted answer.
+    Unsolved,
+    /// The part exceeded its time limit.
+    TimedOut,
+}
+
+impl PartStatus {
+    /// The `status` of the JSON output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Correct => "correct",
+            PartStatus::Wrong => "wrong",
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::TimedOut => "timed_out",
+        }
+    }
+
+    /// Reads a status written by [`PartStatus::as_str`].
+    pub fn parse(status: &str) -> Option<PartStatus> {
+        [
+            PartStatus::Correct,
+            PartStatus::Wrong,
+            PartStatus::Solved,
+            PartStatus::Unsolved,
+            PartStatus::TimedOut,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.as_str() == status)
+    }
+}
+
+/// Outcome of running one part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: DayId,
+    pub part: u8,
+    /// `None` if the solver returned `None`.
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    /// Heap usage, when built with `--features memory`.
+    pub memory: Option<MemoryStats>,
+    /// Repeated timings, when running with `--bench`.
+    pub bench: Option<BenchStats>,
+    /// Accepted answer from `src/answers/NN.txt` or `src/examples/answers/NN.txt`,
+    /// when running on the real input or the example.
+    pub expected: Option<String>,
+    /// The part exceeded its time limit; `elapsed` is the limit.
+    pub timed_out: bool,
+}
+
+impl PartResult {
+    pub fn status(&self) -> PartStatus {
+        match (&self.answer, &self.expected) {
+            _ if self.timed_out => PartStatus::TimedOut,
+            (None, Some(_)) => PartStatus::Wrong,
+            (None, None) => PartStatus::Unsolved,
+            (Some(_), None) => PartStatus::Solved,
+            (Some(answer), Some(expected)) if answers::matches(answer, expected) => PartStatus::Correct,
+            (Some(_), Some(_)) => PartStatus::Wrong,
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self.status(), PartStatus::Wrong)
+    }
+
+    /// Formats the result as a single JSON line:
+    /// `{"day":7,"part":1,"answer":"95437","elapsed_ns":1234,"status":"solved"}`.
+    /// Days of a year carry it as `year` before `day`.
+    /// Results checked against an accepted answer carry it as `expected`.
+    /// With `--features memory`, results carry `allocations`, `allocated_bytes` and `peak_bytes`.
+    /// Benchmarked results additionally carry `runs`, `min_ns`, `median_ns`, `mean_ns`, `p95_ns` and `std_dev_ns`.
+    pub fn to_json(&self) -> String {
+        let object = self
+            .day
+            .to_fields(Object::new())
+            .field("part", Value::Int(self.part.into()))
+            .field(
+                "answer",
+                self.answer.clone().map_or(Value::Null, Value::String),
+            )
+            .field("elapsed_ns", nanos(self.elapsed))
+            .field("status", Value::String(self.status().as_str().into()));
+        let object = match &self.expected {
+            Some(expected) => object.field("expected", Value::String(expected.clone())),
+            None => object,
+        };
+        let object = match &self.memory {
+            Some(stats) => object
+                .field("allocations", bytes(stats.allocations))
+                .field("allocated_bytes", bytes(stats.allocated))
+                .field("peak_bytes", bytes(stats.peak)),
+            None => object,
+        };
+        match &self.bench {
+            Some(stats) => object
+                .field("runs", Value::Int(stats.runs.into()))
+                .field("min_ns", nanos(stats.min))
+                .field("median_ns", nanos(stats.median))
+                .field("mean_ns", nanos(stats.mean))
+                .field("p95_ns", nanos(stats.p95))
+                .field("std_dev_ns", nanos(stats.std_dev))
+                .to_line(),
+            None => object.to_line(),
+        }
+    }
+
+    /// Parses a line produced by [`PartResult::to_json`]. Returns `None` for any other line.
+    pub fn from_json(line: &str) -> Option<PartResult> {
+        let object = Object::parse(line)?;
+        let answer = match object.get("answer")? {
+            Value::Null => None,
+            value => Some(value.as_str()?.to_owned()),
+        };
+        let duration = |key| object.get(key).and_then(Value::as_u64).map(Duration::from_nanos);
+        let bench = match object.get("runs") {
+            Some(runs) => Some(BenchStats {
+                runs: runs.as_u64()?.try_into().ok()?,
+                min: duration("min_ns")?,
+                median: duration("median_ns")?,
+                mean: duration("mean_ns")?,
+                p95: duration("p95_ns")?,
+                std_dev: duration("std_dev_ns")?,
+            }),
+            None => None,
+        };
+        let count = |key| object.get(key).and_then(Value::as_u64);
+        let memory = match count("allocations") {
+            Some(allocations) => Some(MemoryStats {
+                allocations,
+                allocated: count("allocated_bytes")?,
+                peak: count("peak_bytes")?,
+            }),
+            None => None,
+        };
+        Some(PartResult {
+            day: DayId::from_fields(&object)?,
+            part: object.get("part")?.as_u64()?.try_into().ok()?,
+            answer,
+            elapsed: duration("elapsed_ns")?,
+            memory,
+            bench,
+            expected: object.get("expected").and_then(Value::as_str).map(str::to_owned),
+            timed_out: PartStatus::parse(object.get("status")?.as_str()?)? == PartStatus::TimedOut,
+        })
+    }
+
+    pub fn print(&self, mode: OutputMode) {
+        match mode {
+            OutputMode::Json => println!("{}", self.to_json()),
+            OutputMode::Quiet => println!("{}", quiet_line(self.answer.as_deref())),
+            OutputMode::Pretty | OutputMode::Verbose => {
+                let verbose = mode == OutputMode::Verbose;
+                println!("🎄 {}Part {}{} 🎄", bold(), self.part, reset());
+                let answer = match &self.answer {
+                    Some(answer) => answer,
+                    None if self.timed_out => {
+                        println!("timed out after {:.2?}.", self.elapsed);
+                        return;
+                    }
+                    None => {
+                        match (self.is_wrong(), &self.expected) {
+                            (true, Some(expected)) => println!("not solved ✘\nexpected: {}", expected),
+                            _ => println!("not solved."),
+                        }
+                        return;
+                    }
+                };
+                let mut timing = match &self.bench {
+                    None if verbose => format!("elapsed: {:?}", self.elapsed),
+                    None => format!("elapsed: {:.2?}", self.elapsed),
+                    Some(stats) => format!(
+                        "min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, σ: {:.2?}, runs: {}",
+                        stats.min, stats.median, stats.mean, stats.p95, stats.std_dev, stats.runs
+                    ),
+                };
+                if let Some(stats) = &self.memory {
+                    timing = format!("{}, {}", timing, stats);
+                }
+                let mark = match self.status() {
+                    PartStatus::Correct => " ✔",
+                    PartStatus::Wrong => " ✘",
+                    PartStatus::Solved | PartStatus::Unsolved | PartStatus::TimedOut => "",
+                };
+                println!("{}{} {}({}){}", answer, mark, italic(), timing, reset());
+                if let (true, Some(expected)) = (verbose || self.is_wrong(), &self.expected) {
+                    println!("expected: {}", expected);
+                }
+            }
+        }
+    }
+}
+
+/// The answer of a part on a single line for `--quiet`: `-` when there is none, and line
+/// breaks of multi-line answers escaped as `\n` (and backslashes as `\\`).
+fn quiet_line(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) => answer.trim_end().replace('\\', "\\\\").replace('\n', "\\n"),
+        None => "-".into(),
+    }
+}
+
+/// Time spent reading and parsing a day's input, before any part runs, and where it was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputTimings {
+    pub day: DayId,
+    /// `<stdin>` when read from standard input.
+    pub path: PathBuf,
+    pub bytes: usize,
+    pub lines: usize,
+    pub read: Duration,
+    pub parse: Duration,
+}
+
+impl InputTimings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse
+    }
+
+    /// Formats the timings as a single JSON line:
+    /// `{"day":13,"stage":"input","read_ns":15200,"parse_ns":120430,"path":"src/inputs/13.txt","bytes":22046,"lines":449}`.
+    pub fn to_json(&self) -> String {
+        self.day
+            .to_fields(Object::new())
+            .field("stage", Value::String("input".into()))
+            .field("read_ns", nanos(self.read))
+            .field("parse_ns", nanos(self.parse))
+            .field("path", Value::String(self.path.to_string_lossy().into_owned()))
+            .field("bytes", bytes(self.bytes as u64))
+            .field("lines", bytes(self.lines as u64))
+            .to_line()
+    }
+
+    /// Parses a line produced by [`InputTimings::to_json`]. Returns `None` for any other line.
+    pub fn from_json(line: &str) -> Option<InputTimings> {
+        let object = Object::parse(line)?;
+        if object.get("stage")?.as_str()? != "input" {
+            return None;
+        }
+        let count = |key| object.get(key).and_then(Value::as_u64)?.try_into().ok();
+        Some(InputTimings {
+            day: DayId::from_fields(&object)?,
+            path: PathBuf::from(object.get("path")?.as_str()?),
+            bytes: count("bytes")?,
+            lines: count("lines")?,
+            read: Duration::from_nanos(object.get("read_ns")?.as_u64()?),
+            parse: Duration::from_nanos(object.get("parse_ns")?.as_u64()?),
+        })
+    }
+
+    pub fn print(&self, mode: OutputMode) {
+        match mode {
+            OutputMode::Json => println!("{}", self.to_json()),
+            OutputMode::Quiet => {}
+            OutputMode::Pretty => {
+                println!("🎄 {}Input{} 🎄", bold(), reset());
+                println!("{}(read: {:.2?}, parse: {:.2?}){}", italic(), self.read, self.parse, reset());
+            }
+            OutputMode::Verbose => {
+                println!("🎄 {}Input{} 🎄", bold(), reset());
+                println!(
+                    "{} ({}, {} lines)",
+                    self.path.display(),
+                    memory::format_bytes(self.bytes as u64),
+                    self.lines
+                );
+                println!("{}(read: {:?}, parse: {:?}){}", italic(), self.read, self.parse, reset());
+            }
+        }
+    }
+}
+
+/// A line printed by a day binary in JSON mode. Durations are exact nanoseconds, so results
+/// read back from the output are the same as the ones measured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutputLine {
+    Input(InputTimings),
+    Part(PartResult),
+}
+
+impl OutputLine {
+    /// Parses a line produced by [`InputTimings::to_json`] or [`PartResult::to_json`].
+    /// Returns `None` for any other line.
+    pub fn parse(line: &str) -> Option<OutputLine> {
+        InputTimings::from_json(line)
+            .map(OutputLine::Input)
+            .or_else(|| PartResult::from_json(line).map(OutputLine::Part))
+    }
+}
+
+fn nanos(duration: Duration) -> Value {
+    Value::Int(duration.as_nanos().try_into().unwrap_or(i64::MAX))
+}
+
+fn bytes(count: u64) -> Value {
+    Value::Int(count.try_into().unwrap_or(i64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let result = PartResult {
+            day: 10.into(),
+            part: 2,
+            answer: Some("##..\n#..#".into()),
+            elapsed: Duration::from_nanos(1_450_123),
+            memory: None,
+            bench: None,
+            expected: None,
+            timed_out: false,
+        };
+        let line = result.to_json();
+        assert_eq!(
+            line,
+            r###"{"day":10,"part":2,"answer":"##..\n#..#","elapsed_ns":1450123,"status":"solved"}"###
+        );
+        assert_eq!(PartResult::from_json(&line), Some(result));
+
+        let unsolved = PartResult {
+            day: 3.into(),
+            part: 1,
+            answer: None,
+            elapsed: Duration::from_nanos(50),
+            memory: None,
+            bench: None,
+            expected: None,
+            timed_out: false,
+        };
+        assert_eq!(unsolved.status(), PartStatus::Unsolved);
+        assert_eq!(PartResult::from_json(&unsolved.to_json()), Some(unsolved.clone()));
+
+        let lost = PartResult {
+            expected: Some("157".into()),
+            ..unsolved
+        };
+        assert_eq!(lost.status(), PartStatus::Wrong);
+        assert!(lost.is_wrong());
+        assert_eq!(PartResult::from_json(&lost.to_json()), Some(lost));
+
+        let benched = PartResult {
+            day: 1.into(),
+            part: 1,
+            answer: Some("24000".into()),
+            elapsed: Duration::from_nanos(2500),
+            memory: Some(MemoryStats {
+                allocations: 3,
+                allocated: 4096,
+                peak: 2048,
+            }),
+            bench: Some(BenchStats {
+                runs: 100,
+                min: Duration::from_nanos(2000),
+                median: Duration::from_nanos(2100),
+                mean: Duration::from_nanos(2150),
+                p95: Duration::from_nanos(2400),
+                std_dev: Duration::from_nanos(90),
+            }),
+            expected: Some("24000".into()),
+            timed_out: false,
+        };
+        assert_eq!(benched.status(), PartStatus::Correct);
+        assert_eq!(PartResult::from_json(&benched.to_json()), Some(benched.clone()));
+
+        let wrong = PartResult {
+            answer: Some("24001".into()),
+            ..benched
+        };
+        assert!(wrong.is_wrong());
+        assert_eq!(PartResult::from_json(&wrong.to_json()), Some(wrong));
+
+        let timed_out = PartResult {
+            day: 15.into(),
+            part: 2,
+            answer: None,
+            elapsed: Duration::from_secs(5),
+            memory: None,
+            bench: None,
+            expected: None,
+            timed_out: true,
+        };
+        assert_eq!(timed_out.status(), PartStatus::TimedOut);
+        assert_eq!(PartResult::from_json(&timed_out.to_json()), Some(timed_out));
+        assert_eq!(PartStatus::parse("timed_out"), Some(PartStatus::TimedOut));
+        assert_eq!(PartStatus::parse("timed out"), None);
+    }
+
+    #[test]
+    fn test_quiet_line() {
+        assert_eq!(quiet_line(Some("CMZ")), "CMZ");
+        assert_eq!(quiet_line(None), "-");
+        assert_eq!(quiet_line(Some("##..\n#..\\\n")), "##..\\n#..\\\\");
+    }
+
+    #[test]
+    fn test_input_timings_json_round_trip() {
+        let timings = InputTimings {
+            day: (2022, 13).into(),
+            path: "src/inputs/13.txt".into(),
+            bytes: 22_046,
+            lines: 449,
+            read: Duration::from_nanos(15_200),
+            parse: Duration::from_nanos(120_430),
+        };
+        let line = timings.to_json();
+        assert_eq!(
+            line,
+            r#"{"year":2022,"day":13,"stage":"input","read_ns":15200,"parse_ns":120430,"path":"src/inputs/13.txt","bytes":22046,"lines":449}"#
+        );
+        assert_eq!(InputTimings::from_json(&line), Some(timings.clone()));
+        assert_eq!(PartResult::from_json(&line), None);
+
+        assert_eq!(PartResult::from_json("🎄 Part 1 🎄"), None);
+    }
+}
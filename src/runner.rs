@@ -0,0 +1,497 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::answers::Answers;
+use crate::args::{DayArgs, InputSource};
+use crate::bench::BenchConfig;
+use crate::day::DayId;
+use crate::days;
+use crate::input::{read_source, InputError};
+use crate::isolate;
+use crate::report::{InputTimings, OutputLine, OutputMode, PartResult};
+use crate::solution::{DynSolution, ParsedInput, Solved};
+
+/// Exit status for a wrong answer, or a part that got slower with `cargo all -- --compare`.
+pub const EXIT_WRONG_ANSWER: i32 = 1;
+/// Exit status for bad command line arguments.
+pub const EXIT_USAGE: i32 = 2;
+/// Exit status when a day could not be run: its input didn't load, it panicked or didn't build.
+pub const EXIT_FAILED: i32 = 3;
+
+/// How [`run_day`] runs the parts of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Time each part repeatedly after solving it.
+    pub bench: Option<BenchConfig>,
+    /// Give up on a part after this long. In-process, the part then runs on a worker thread
+    /// that is left behind if it doesn't finish, since a solver can't be interrupted.
+    /// With `isolate`, each part runs in its own child process, which ends with the part.
+    pub timeout: Option<Duration>,
+    /// Run only this part. Both parts run when `None`.
+    pub part: Option<u8>,
+    /// Make [`run_days`] run each day's binary in a child process instead of in-process.
+    pub isolate: bool,
+}
+
+/// Everything measured while running one day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayReport {
+    pub input: InputTimings,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayReport {
+    pub fn has_wrong_answer(&self) -> bool {
+        self.parts.iter().any(PartResult::is_wrong)
+    }
+
+    pub fn has_timed_out(&self) -> bool {
+        self.parts.iter().any(|part| part.timed_out)
+    }
+
+    /// Collects the JSON lines a day binary printed to stdout, skipping anything else.
+    /// Returns `None` unless they hold the input timings and the selected parts of a single day,
+    /// both parts when `part` is `None`.
+    pub fn from_output(stdout: &str, part: Option<u8>) -> Option<DayReport> {
+        let mut input = None;
+        let mut parts = Vec::new();
+        for line in stdout.lines().filter_map(OutputLine::parse) {
+            match line {
+                OutputLine::Input(timings) if input.is_none() => input = Some(timings),
+                OutputLine::Input(_) => return None,
+                OutputLine::Part(result) => parts.push(result),
+            }
+        }
+        let input = input?;
+        let selected: Vec<u8> = (1..=2).filter(|&n| part.is_none_or(|only| only == n)).collect();
+        let complete = parts.iter().all(|result| result.day == input.day)
+            && parts.iter().map(|result| result.part).eq(selected);
+        complete.then_some(DayReport { input, parts })
+    }
+
+    pub fn print(&self, mode: OutputMode) {
+        self.input.print(mode);
+        for part in &self.parts {
+            part.print(mode);
+        }
+    }
+}
+
+/// Reads, parses and solves both parts of a day in-process.
+/// On the real input and the example, answers are checked against the recorded ones, see [`Answers`].
+pub fn run_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    options: &RunOptions,
+) -> Result<DayReport, InputError> {
+    let day = solution.id();
+
+    let timer = Instant::now();
+    let (path, contents) = read_source(source, day)?;
+    let read = timer.elapsed();
+
+    let timer = Instant::now();
+    let input: Arc<dyn ParsedInput> = solution.parse(&path, &contents)?.into();
+    let parse = timer.elapsed();
+
+    let answers = Answers::for_source(day, source);
+    let parts = (1..=2)
+        .filter(|&part| options.part.is_none_or(|only| only == part))
+        .map(|part| {
+            let expected = answers.get(part).map(str::to_owned);
+            let solved = match options.timeout {
+                None => input.solve(part),
+                Some(limit) => match solve_with_timeout(&input, part, limit) {
+                    Some(solved) => solved,
+                    None => {
+                        return PartResult {
+                            day,
+                            part,
+                            answer: None,
+                            elapsed: limit,
+                            memory: None,
+                            bench: None,
+                            expected,
+                            timed_out: true,
+                        }
+                    }
+                },
+            };
+            let bench = options
+                .bench
+                .filter(|_| solved.answer.is_some())
+                .map(|config| input.bench(part, &config));
+            PartResult {
+                day,
+                part,
+                answer: solved.answer,
+                elapsed: solved.elapsed,
+                memory: solved.memory,
+                bench,
+                expected,
+                timed_out: false,
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        input: InputTimings {
+            day,
+            path,
+            bytes: contents.len(),
+            lines: contents.lines().count(),
+            read,
+            parse,
+        },
+        parts,
+    })
+}
+
+/// Why [`run_days`] has no report for a day.
+#[derive(Debug)]
+pub enum DayError {
+    /// There is no solution for the day, or no binary with `--isolate`.
+    NotPresent,
+    /// The binary didn't compile (`--isolate` only).
+    BuildFailed { stderr: String },
+    /// The input could not be read or parsed.
+    Input(InputError),
+    /// A part panicked.
+    Panicked { message: String },
+    /// The binary exited with a failure status without producing results (`--isolate` only).
+    Exited { code: Option<i32>, stderr: String },
+    /// The binary was killed for running far longer than `--timeout` (`--isolate` only).
+    TimedOut { limit: Duration },
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::NotPresent => write!(f, "Not solved."),
+            DayError::BuildFailed { stderr } => {
+                write!(f, "Failed to build:\n{}", stderr.trim_end())
+            }
+            DayError::Input(e) => write!(f, "{}", e),
+            DayError::Panicked { message } => write!(f, "Panicked: {}", message),
+            DayError::Exited {
+                code: Some(code),
+                stderr,
+            } => {
+                write!(f, "Exited with status {}:\n{}", code, stderr.trim_end())
+            }
+            DayError::Exited { code: None, stderr } => {
+                write!(f, "Killed by a signal:\n{}", stderr.trim_end())
+            }
+            DayError::TimedOut { limit } => write!(f, "Killed after {:.2?} without a result.", limit),
+        }
+    }
+}
+
+impl std::error::Error for DayError {}
+
+impl DayError {
+    /// A few words for tables, e.g. `panicked`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DayError::NotPresent => "not solved",
+            DayError::BuildFailed { .. } => "build failed",
+            DayError::Input(_) => "input error",
+            DayError::Panicked { .. } => "panicked",
+            DayError::Exited { .. } => "exited",
+            DayError::TimedOut { .. } => "timed out",
+        }
+    }
+}
+
+impl From<InputError> for DayError {
+    fn from(e: InputError) -> DayError {
+        DayError::Input(e)
+    }
+}
+
+/// Extracts the message of a panic payload, which is a `&str` or `String` for `panic!`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Runs one day of [`run_days`], in-process with panics caught or as a child process.
+fn run_any(day: DayId, source: &InputSource, options: &RunOptions) -> Result<DayReport, DayError> {
+    if options.isolate {
+        return isolate::run_day(day, source, options);
+    }
+    let solution = days::get(day).ok_or(DayError::NotPresent)?;
+    match panic::catch_unwind(AssertUnwindSafe(|| run_day(solution, source, options))) {
+        Ok(report) => Ok(report?),
+        Err(payload) => Err(DayError::Panicked {
+            message: panic_message(&*payload),
+        }),
+    }
+}
+
+/// Runs each of `days` on up to `jobs` threads and yields the reports in the order of
+/// `days`, each as soon as it and all before it are done. A day that panics or can't
+/// be run yields a [`DayError`] instead of ending the run.
+///
+/// Every day is timed on its own thread, but days running at the same time compete
+/// for the CPU, so use more jobs than cores only when the timings don't matter.
+/// With a single job the days run on the calling thread as the iterator advances.
+pub fn run_days(
+    days: Vec<DayId>,
+    source: InputSource,
+    options: RunOptions,
+    jobs: usize,
+) -> Box<dyn Iterator<Item = (DayId, Result<DayReport, DayError>)>> {
+    if jobs <= 1 {
+        return Box::new(
+            days.into_iter()
+                .map(move |day| (day, run_any(day, &source, &options))),
+        );
+    }
+
+    let len = days.len();
+    let days = Arc::new(days);
+    let next = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = mpsc::channel();
+    let workers = (0..jobs.min(len))
+        .map(|_| {
+            let days = Arc::clone(&days);
+            let next = Arc::clone(&next);
+            let sender = sender.clone();
+            let source = source.clone();
+            let options = options.clone();
+            thread::spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let result = (day, run_any(day, &source, &options));
+                if sender.send((index, result)).is_err() {
+                    break;
+                }
+            })
+        })
+        .collect();
+
+    Box::new(InOrder {
+        receiver,
+        pending: BTreeMap::new(),
+        next: 0,
+        len,
+        workers,
+    })
+}
+
+type DayResult = (DayId, Result<DayReport, DayError>);
+
+/// Reorders the reports sent by the workers of [`run_days`].
+struct InOrder {
+    receiver: mpsc::Receiver<(usize, DayResult)>,
+    pending: BTreeMap<usize, DayResult>,
+    next: usize,
+    len: usize,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Iterator for InOrder {
+    type Item = DayResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            return None;
+        }
+        while !self.pending.contains_key(&self.next) {
+            match self.receiver.recv() {
+                Ok((index, report)) => {
+                    self.pending.insert(index, report);
+                }
+                // all workers are gone without sending this day, so one of them panicked.
+                Err(_) => {
+                    for worker in self.workers.drain(..) {
+                        if let Err(payload) = worker.join() {
+                            panic::resume_unwind(payload);
+                        }
+                    }
+                    unreachable!("workers finished without running every day");
+                }
+            }
+        }
+        let report = self.pending.remove(&self.next);
+        self.next += 1;
+        report
+    }
+}
+
+/// Solves `part` on a worker thread and waits at most `limit` for it.
+/// Returns `None` on timeout; a panic in the part is propagated.
+fn solve_with_timeout(
+    input: &Arc<dyn ParsedInput>,
+    part: u8,
+    limit: Duration,
+) -> Option<Solved> {
+    let (sender, receiver) = mpsc::channel();
+    let input = Arc::clone(input);
+    let handle = thread::spawn(move || {
+        // the receiver is gone if we already gave up on this part.
+        let _ = sender.send(input.solve(part));
+    });
+    match receiver.recv_timeout(limit) {
+        Ok(solved) => Some(solved),
+        Err(mpsc::RecvTimeoutError::Timeout) => None,
+        Err(mpsc::RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("worker finished without sending a result"),
+        },
+    }
+}
+
+/// Entry point of the day binaries in `src/bin`: runs `solution` on the input selected
+/// by [`DayArgs`] and prints the result. Exits with [`EXIT_FAILED`] if the input can't be
+/// read or parsed or a part timed out, or with [`EXIT_WRONG_ANSWER`] if an answer differs
+/// from the recorded one.
+pub fn main(solution: &dyn DynSolution) {
+    let args = DayArgs::get();
+    let options = RunOptions {
+        bench: args.bench,
+        timeout: args.timeout,
+        part: args.part,
+        ..RunOptions::default()
+    };
+    match run_day(solution, &args.input, &options) {
+        Ok(report) => {
+            report.print(OutputMode::get());
+            // a timed-out part keeps its worker thread busy until the process exits.
+            let first_timeout = report.parts.iter().position(|part| part.timed_out);
+            if let Some(index) = first_timeout.filter(|&index| index + 1 < report.parts.len()) {
+                eprintln!(
+                    "warning: part {} kept running after it timed out, so the timings of the parts after it are unreliable.",
+                    report.parts[index].part
+                );
+            }
+            if report.has_timed_out() {
+                process::exit(EXIT_FAILED);
+            }
+            if report.has_wrong_answer() {
+                process::exit(EXIT_WRONG_ANSWER);
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(EXIT_FAILED);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::BenchStats;
+
+    #[test]
+    fn test_run_day_on_example() {
+        let solution = days::get(1).unwrap();
+        let report = run_day(solution, &InputSource::Examples, &RunOptions::default()).unwrap();
+        assert_eq!(report.input.day, 1.into());
+        let answers: Vec<_> = report.parts.iter().map(|part| part.answer.as_deref()).collect();
+        assert_eq!(answers, vec![Some("24000"), Some("45000")]);
+        assert!(!report.has_wrong_answer());
+
+        let options = RunOptions {
+            part: Some(2),
+            ..RunOptions::default()
+        };
+        let report = run_day(solution, &InputSource::Examples, &options).unwrap();
+        let parts: Vec<_> = report.parts.iter().map(|part| part.part).collect();
+        assert_eq!(parts, vec![2]);
+    }
+
+    #[test]
+    fn test_run_days_keeps_order() {
+        let days: Vec<DayId> = (1..=4).map(DayId::from).collect();
+        let reports = run_days(days.clone(), InputSource::Examples, RunOptions::default(), 3);
+        let reported: Vec<_> = reports
+            .map(|(_, report)| report.unwrap().input.day)
+            .collect();
+        assert_eq!(reported, days);
+
+        let mut reports = run_days(
+            vec![25.into(), (2022, 1).into()],
+            InputSource::Examples,
+            RunOptions::default(),
+            1,
+        );
+        assert!(matches!(reports.next(), Some((_, Err(DayError::NotPresent)))));
+        assert!(matches!(reports.next(), Some((_, Err(DayError::NotPresent)))));
+    }
+
+    #[test]
+    fn test_day_report_from_output() {
+        let stdout = r#"Compiling advent_of_code
+{"day":7,"stage":"input","read_ns":15200,"parse_ns":120430,"path":"src/inputs/07.txt","bytes":10,"lines":2}
+{"day":7,"part":1,"answer":"95437","elapsed_ns":74,"status":"solved"}
+{"day":7,"part":2,"answer":null,"elapsed_ns":50,"status":"unsolved"}
+"#;
+        let report = DayReport::from_output(stdout, None).unwrap();
+        assert_eq!(report.input.parse, Duration::from_nanos(120_430));
+        assert_eq!(report.parts[0].elapsed, Duration::from_nanos(74));
+        assert_eq!(report.parts[1].answer, None);
+
+        let first_part: String = stdout.lines().take(3).map(|line| line.to_owned() + "\n").collect();
+        assert_eq!(DayReport::from_output(&first_part, None), None);
+        assert_eq!(DayReport::from_output(&first_part, Some(1)).unwrap().parts.len(), 1);
+        assert_eq!(DayReport::from_output(stdout, Some(1)), None);
+        assert_eq!(DayReport::from_output(&stdout.replace("\"day\":7,\"part\":2", "\"day\":8,\"part\":2"), None), None);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload =
+            panic::catch_unwind(|| panic!("no path from {} to {}", 'S', 'E')).unwrap_err();
+        assert_eq!(panic_message(&*payload), "no path from S to E");
+        let payload = panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(panic_message(&*payload), "static");
+    }
+
+    struct Sleepy;
+
+    impl ParsedInput for Sleepy {
+        fn solve(&self, part: u8) -> Solved {
+            let elapsed = Duration::from_millis(if part == 1 { 0 } else { 500 });
+            thread::sleep(elapsed);
+            Solved {
+                answer: Some(part.to_string()),
+                elapsed,
+                memory: None,
+            }
+        }
+
+        fn bench(&self, _: u8, _: &BenchConfig) -> BenchStats {
+            BenchStats::from_samples(&mut [Duration::ZERO])
+        }
+    }
+
+    #[test]
+    fn test_solve_with_timeout() {
+        let input: Arc<dyn ParsedInput> = Arc::new(Sleepy);
+        let limit = Duration::from_millis(100);
+        let solved = solve_with_timeout(&input, 1, limit).unwrap();
+        assert_eq!(solved.answer.as_deref(), Some("1"));
+        assert_eq!(solve_with_timeout(&input, 2, limit), None);
+    }
+}
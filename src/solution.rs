@@ -0,0 +1,104 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use nom::IResult;
+
+use crate::bench::{self, BenchConfig, BenchStats};
+use crate::day::DayId;
+use crate::input::{parse_input, InputError};
+use crate::memory::{self, MemoryStats};
+
+/// A day's puzzle: a nom parser for the input and a solver for each part.
+///
+/// Implement it on a unit struct in `src/days/dayNN.rs` and list that struct in
+/// [`crate::days::ALL`] to make the day known to the runner and other tools.
+pub trait Solution {
+    const DAY: u8;
+    /// The year of the puzzle, in repositories that hold several years.
+    const YEAR: Option<u16> = None;
+
+    type Input: Send + Sync + 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> IResult<&str, Self::Input>;
+    fn part_one(input: &Self::Input) -> Option<Self::Answer1>;
+    fn part_two(input: &Self::Input) -> Option<Self::Answer2>;
+}
+
+/// Object-safe view of a [`Solution`], so days with different input and answer
+/// types can be stored in one registry and invoked generically.
+pub trait DynSolution: Sync {
+    fn id(&self) -> DayId;
+
+    /// Parses `input`, which must be consumed entirely. `path` is only used in errors.
+    fn parse(&self, path: &Path, input: &str) -> Result<Box<dyn ParsedInput>, InputError>;
+}
+
+/// Answer of one part with the time, and possibly memory, its solver took.
+/// Formatting the answer is not included in either.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    /// `None` unless built with `--features memory`.
+    pub memory: Option<MemoryStats>,
+}
+
+/// A parsed input, ready to have its parts solved.
+pub trait ParsedInput: Send + Sync {
+    /// Runs part 1 or 2.
+    fn solve(&self, part: u8) -> Solved;
+
+    /// Times part 1 or 2 repeatedly, see [`bench::bench`].
+    fn bench(&self, part: u8, config: &BenchConfig) -> BenchStats;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+fn time<T: Display>(func: impl FnOnce() -> Option<T>) -> Solved {
+    let ((result, elapsed), memory) = memory::measure(|| {
+        let timer = Instant::now();
+        let result = func();
+        (result, timer.elapsed())
+    });
+    Solved {
+        answer: result.map(|result| result.to_string()),
+        elapsed,
+        memory,
+    }
+}
+
+impl<S: Solution + 'static> ParsedInput for Parsed<S> {
+    fn solve(&self, part: u8) -> Solved {
+        match part {
+            1 => time(|| S::part_one(&self.0)),
+            2 => time(|| S::part_two(&self.0)),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+
+    fn bench(&self, part: u8, config: &BenchConfig) -> BenchStats {
+        match part {
+            1 => bench::bench(config, || S::part_one(&self.0)),
+            2 => bench::bench(config, || S::part_two(&self.0)),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+}
+
+impl<S: Solution + Sync + 'static> DynSolution for S {
+    fn id(&self) -> DayId {
+        DayId::new(S::YEAR, S::DAY)
+    }
+
+    fn parse(&self, path: &Path, input: &str) -> Result<Box<dyn ParsedInput>, InputError> {
+        let input = parse_input(path, input, S::parse)?;
+        Ok(Box::new(Parsed::<S>(input)))
+    }
+}
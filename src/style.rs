@@ -0,0 +1,57 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::ffi::OsStr;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Whether the human-readable output is styled with ANSI escape codes: only when stdout
+/// is a terminal and `NO_COLOR` is unset or empty, see https://no-color.org.
+pub fn color_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| use_color(env::var_os("NO_COLOR").as_deref(), io::stdout().is_terminal()))
+}
+
+fn use_color(no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    is_terminal && no_color.is_none_or(OsStr::is_empty)
+}
+
+fn code(code: &'static str) -> &'static str {
+    if color_enabled() {
+        code
+    } else {
+        ""
+    }
+}
+
+/// [`ANSI_BOLD`], or nothing if color is disabled.
+pub fn bold() -> &'static str {
+    code(ANSI_BOLD)
+}
+
+/// [`ANSI_ITALIC`], or nothing if color is disabled.
+pub fn italic() -> &'static str {
+    code(ANSI_ITALIC)
+}
+
+/// [`ANSI_RESET`], or nothing if color is disabled.
+pub fn reset() -> &'static str {
+    code(ANSI_RESET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_color() {
+        assert!(use_color(None, true));
+        assert!(use_color(Some(OsStr::new("")), true));
+        assert!(!use_color(Some(OsStr::new("1")), true));
+        assert!(!use_color(None, false));
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::day::DayId;
+use crate::report::PartResult;
+use crate::runner::{DayError, DayReport};
+use crate::table::Table;
+
+/// Marks the start of the section of README.md rewritten by `cargo all -- --update-readme`.
+pub const README_START: &str = "<!--- runtimes --->";
+/// Marks the end of that section.
+pub const README_END: &str = "<!--- runtimes end --->";
+
+/// One row per day with the answer and time of each part and the parse time.
+pub fn summary_table(results: &[(DayId, Result<DayReport, DayError>)]) -> Table {
+    let mut table = Table::new(&["Day", "Part 1", "Time", "Part 2", "Time", "Parse"]);
+    for (day, result) in results {
+        let mut row = vec![day.to_string()];
+        match result {
+            Ok(report) => {
+                for part in 1..=2 {
+                    match report.parts.iter().find(|result| result.part == part) {
+                        Some(result) => {
+                            row.push(answer_cell(result));
+                            row.push(format!("{:.2?}", result.elapsed));
+                        }
+                        None => row.extend(["-".to_owned(), "-".to_owned()]),
+                    }
+                }
+                row.push(format!("{:.2?}", report.input.parse));
+            }
+            Err(e) => {
+                let kind = e.kind().to_owned();
+                row.extend([kind.clone(), "-".to_owned(), kind, "-".to_owned(), "-".to_owned()]);
+            }
+        }
+        table.push(row);
+    }
+    table
+}
+
+/// Answers spanning several lines are cut to their first line, so each day fits on one row.
+fn answer_cell(result: &PartResult) -> String {
+    let answer = match (&result.answer, result.timed_out) {
+        (_, true) => return "timed out".into(),
+        (None, _) if result.is_wrong() => return "not solved ✘".into(),
+        (None, _) => return "not solved".into(),
+        (Some(answer), _) => answer,
+    };
+    let mut lines = answer.lines();
+    let mut cell = lines.next().unwrap_or_default().to_owned();
+    if lines.next().is_some() {
+        cell.push_str(" …");
+    }
+    if result.is_wrong() {
+        cell.push_str(" ✘");
+    }
+    cell
+}
+
+/// Replaces the lines between [`README_START`] and [`README_END`] in `text` with `section`.
+/// Returns `None` if the markers are missing or out of order.
+pub fn replace_section(text: &str, section: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len() + section.len());
+    let mut lines = text.split_inclusive('\n');
+    for line in lines.by_ref() {
+        out.push_str(line);
+        if line.trim_end() == README_START {
+            break;
+        }
+    }
+    if out.len() == text.len() {
+        return None;
+    }
+    // skip the old section up to the end marker, which is kept.
+    let rest: Vec<&str> = lines
+        .skip_while(|line| line.trim_end() != README_END)
+        .collect();
+    if rest.is_empty() {
+        return None;
+    }
+    out.push_str(section);
+    if !section.is_empty() && !section.ends_with('\n') {
+        out.push('\n');
+    }
+    out.extend(rest);
+    Some(out)
+}
+
+/// Rewrites the marked section of the README at `path`.
+pub fn update_readme(path: &Path, section: &str) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let updated = replace_section(&text, section).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} has no section between {} and {}",
+                path.display(),
+                README_START,
+                README_END
+            ),
+        )
+    })?;
+    fs::write(path, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::InputTimings;
+    use std::time::Duration;
+
+    fn part(part: u8, answer: &str, expected: Option<&str>) -> PartResult {
+        PartResult {
+            day: 10.into(),
+            part,
+            answer: Some(answer.into()),
+            elapsed: Duration::from_micros(12),
+            memory: None,
+            bench: None,
+            expected: expected.map(str::to_owned),
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let report = DayReport {
+            input: InputTimings {
+                day: 10.into(),
+                path: "src/inputs/10.txt".into(),
+                bytes: 980,
+                lines: 146,
+                read: Duration::from_micros(5),
+                parse: Duration::from_micros(30),
+            },
+            parts: vec![part(1, "13140", Some("13141")), part(2, "##..\n#..#\n", None)],
+        };
+        let panicked = DayError::Panicked { message: "oops".into() };
+        let table = summary_table(&[(10.into(), Ok(report)), (11.into(), Err(panicked))]);
+        let markdown = table.render_markdown();
+        let rows: Vec<_> = markdown.lines().skip(2).collect();
+        assert_eq!(rows[0], "| 10 | 13140 ✘ | 12.00µs | ##.. … | 12.00µs | 30.00µs |");
+        // error rows fill every column.
+        assert_eq!(rows[1], "| 11 | panicked | - | panicked | - | - |");
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let text = format!("# Title\n{}\nold\n{}\nfooter\n", README_START, README_END);
+        assert_eq!(
+            replace_section(&text, "new"),
+            Some(format!("# Title\n{}\nnew\n{}\nfooter\n", README_START, README_END))
+        );
+        assert_eq!(replace_section("# Title\n", "new"), None);
+        assert_eq!(replace_section(&format!("{}\nold\n", README_START), "new"), None);
+        // markers quoted inside a line don't count.
+        assert_eq!(replace_section(&format!("see `{}`\n", README_START), "new"), None);
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// A table of text cells with a header row, for the summaries of the aggregate runner.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Table {
+        Table {
+            header: header.iter().map(|&cell| cell.to_owned()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row. Missing cells are left empty.
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.header.iter().map(|cell| cell.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths
+    }
+
+    /// Renders the table with columns aligned by padding, for the terminal.
+    pub fn render(&self) -> String {
+        let widths = self.widths();
+        let mut out = String::new();
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            let cells: Vec<String> = widths
+                .iter()
+                .enumerate()
+                .map(|(i, &width)| {
+                    let cell = row.get(i).map_or("", String::as_str);
+                    format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                })
+                .collect();
+            out.push_str(cells.join("  ").trim_end());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders the table as GitHub-flavored Markdown.
+    pub fn render_markdown(&self) -> String {
+        let line = |row: &[String]| {
+            let cells: Vec<String> = (0..self.header.len())
+                .map(|i| row.get(i).map_or(String::new(), |cell| cell.replace('|', "\\|")))
+                .collect();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        let mut out = line(&self.header);
+        out.push_str(&format!("|{}\n", " --- |".repeat(self.header.len())));
+        for row in &self.rows {
+            out.push_str(&line(row));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut table = Table::new(&["Day", "Part 1", "Part 2"]);
+        table.push(vec!["01".into(), "pass".into(), "pass".into()]);
+        table.push(vec!["10".into(), "not solved".into()]);
+        assert_eq!(
+            table.render(),
+            "Day  Part 1      Part 2\n01   pass        pass\n10   not solved\n"
+        );
+        assert_eq!(
+            table.render_markdown(),
+            "| Day | Part 1 | Part 2 |\n| --- | --- | --- |\n| 01 | pass | pass |\n| 10 | not solved |  |\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes_pipes() {
+        let mut table = Table::new(&["Answer"]);
+        table.push(vec!["a|b".into()]);
+        assert!(table.render_markdown().ends_with("| a\\|b |\n"));
+    }
+}
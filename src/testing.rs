@@ -0,0 +1,257 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{Debug, Display};
+use std::fs;
+
+use nom::{error::Error, IResult};
+
+use crate::answers::{self, Answers};
+use crate::args::InputSource;
+use crate::day::DayId;
+use crate::input::{parse_input, read_source};
+
+/// Generates the tests of a day module from its expected answers.
+///
+/// The short form checks the day's example `src/examples/NN.txt` and the real input:
+///
+/// ```ignore
+/// crate::aoc_tests!(day = 5, parser = input_parser, part_one = Some("CMZ"), part_two = Some("MCD"));
+/// ```
+///
+/// Days with several examples list them in blocks: `example` is `src/examples/NN.txt`,
+/// `example larger` the named example `src/examples/NN-larger.txt` and `input` the real input
+/// `src/inputs/NN.txt`.
+///
+/// ```ignore
+/// crate::aoc_tests! {
+///     day = 9,
+///     parser = input_parser,
+///     example { part_one = Some(13), part_two = Some(1) }
+///     example larger { part_two = Some(36) }
+///     input {}
+/// }
+/// ```
+///
+/// Each part gets a test named `test_part_one` or `test_part_two`, inside a module named after
+/// the block. Parts left out are not tested and `None` checks that a part is not solved yet.
+/// Without any checks, as for the input of the short form or an empty block, both parts are
+/// checked against the recorded answers, see [`Answers`].
+///
+/// Input tests only run with `cargo test --features input-tests`, as inputs are personal and
+/// not committed. They pass without checking when the input is missing or empty, and skip parts
+/// without a recorded answer. An input that fails to parse fails the test with the location of
+/// the error.
+#[macro_export]
+macro_rules! aoc_tests {
+    (day = $day:expr, parser = $parser:expr $(, $($checks:tt)*)?) => {
+        #[cfg(test)]
+        mod tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::aoc_tests!(@form ($day, $parser) $($($checks)*)?);
+        }
+    };
+
+    // the short form, whose checks are for the day's example.
+    (@form $args:tt) => {
+        $crate::aoc_tests!(@blocks $args example {} input {});
+    };
+    (@form $args:tt example $($rest:tt)*) => {
+        $crate::aoc_tests!(@blocks $args example $($rest)*);
+    };
+    (@form $args:tt input $($rest:tt)*) => {
+        $crate::aoc_tests!(@blocks $args input $($rest)*);
+    };
+    (@form $args:tt $($checks:tt)+) => {
+        $crate::aoc_tests!(@blocks $args example { $($checks)+ } input {});
+    };
+
+    (@blocks $args:tt) => {};
+    (@blocks $args:tt example { $($checks:tt)* } $($rest:tt)*) => {
+        mod example {
+            use super::*;
+
+            $crate::aoc_tests!(@checks $args ($crate::args::InputSource::Examples) $($checks)*);
+        }
+        $crate::aoc_tests!(@blocks $args $($rest)*);
+    };
+    (@blocks $args:tt example $name:ident { $($checks:tt)* } $($rest:tt)*) => {
+        mod $name {
+            use super::*;
+
+            $crate::aoc_tests!(
+                @checks $args ($crate::args::InputSource::NamedExample(stringify!($name).into())) $($checks)*
+            );
+        }
+        $crate::aoc_tests!(@blocks $args $($rest)*);
+    };
+    (@blocks $args:tt input { $($checks:tt)* } $($rest:tt)*) => {
+        #[cfg(feature = "input-tests")]
+        mod input {
+            use super::*;
+
+            $crate::aoc_tests!(@checks $args ($crate::args::InputSource::Inputs) $($checks)*);
+        }
+        $crate::aoc_tests!(@blocks $args $($rest)*);
+    };
+    (@checks $args:tt $source:tt) => {
+        $crate::aoc_tests!(@test test_part_one, part_one, 1, $args, $source, check_recorded);
+        $crate::aoc_tests!(@test test_part_two, part_two, 2, $args, $source, check_recorded);
+    };
+    (@checks $args:tt $source:tt $($checks:tt)+) => {
+        $crate::aoc_tests!(@parts $args $source $($checks)+);
+    };
+
+    (@parts $args:tt $source:tt $(,)?) => {};
+    (@parts $args:tt $source:tt part_one = None $(, $($rest:tt)*)?) => {
+        $crate::aoc_tests!(@test test_part_one, part_one, 1, $args, $source, check_unsolved);
+        $crate::aoc_tests!(@parts $args $source $($($rest)*)?);
+    };
+    (@parts $args:tt $source:tt part_one = $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::aoc_tests!(@test test_part_one, part_one, 1, $args, $source, check_answer, $expected);
+        $crate::aoc_tests!(@parts $args $source $($($rest)*)?);
+    };
+    (@parts $args:tt $source:tt part_two = None $(, $($rest:tt)*)?) => {
+        $crate::aoc_tests!(@test test_part_two, part_two, 2, $args, $source, check_unsolved);
+        $crate::aoc_tests!(@parts $args $source $($($rest)*)?);
+    };
+    (@parts $args:tt $source:tt part_two = $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::aoc_tests!(@test test_part_two, part_two, 2, $args, $source, check_answer, $expected);
+        $crate::aoc_tests!(@parts $args $source $($($rest)*)?);
+    };
+
+    (@test $name:ident, $solver:ident, $part:literal, ($day:expr, $parser:expr), ($source:expr), check_recorded) => {
+        #[test]
+        fn $name() {
+            let source = $source;
+            if let Some(input) = $crate::testing::load(&source, $day, $parser) {
+                $crate::testing::check_recorded($part, $solver(&input), &source, $day);
+            }
+        }
+    };
+    (@test $name:ident, $solver:ident, $part:literal, ($day:expr, $parser:expr), ($source:expr), $check:ident $(, $expected:expr)?) => {
+        #[test]
+        fn $name() {
+            if let Some(input) = $crate::testing::load(&$source, $day, $parser) {
+                $crate::testing::$check($part, $solver(&input) $(, $expected)?);
+            }
+        }
+    };
+}
+
+/// Reads and parses the input of a test generated by [`aoc_tests!`]. Returns `None` when the
+/// real input is missing or empty, as on a fresh checkout. Panics with the location of a parse
+/// error.
+pub fn load<F, O>(source: &InputSource, day: impl Into<DayId>, parser: F) -> Option<O>
+where
+    F: for<'input> Fn(&'input str) -> IResult<&'input str, O, Error<&'input str>>,
+{
+    let day = day.into();
+    if let InputSource::Inputs = source {
+        let path = day.path("inputs");
+        if !fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            eprintln!("skipped: {} is missing or empty", day.relative_path("inputs").display());
+            return None;
+        }
+    }
+    let input = read_source(source, day)
+        .and_then(|(path, contents)| parse_input(&path, &contents, parser))
+        .unwrap_or_else(|e| panic!("{}", e));
+    Some(input)
+}
+
+/// Checks the answer of a part against the expected one. Answers only need to compare equal,
+/// so a `String` answer can be given as `Some("CMZ")`.
+#[track_caller]
+pub fn check_answer<A, E>(part: u8, answer: Option<A>, expected: Option<E>)
+where
+    A: PartialEq<E> + Debug,
+    E: Debug,
+{
+    let matches = match (&answer, &expected) {
+        (Some(answer), Some(expected)) => answer == expected,
+        (None, None) => true,
+        _ => false,
+    };
+    assert!(matches, "part {}: expected {:?}, got {:?}", part, expected, answer);
+}
+
+/// Checks that a part is not solved yet.
+#[track_caller]
+pub fn check_unsolved<A: Debug>(part: u8, answer: Option<A>) {
+    assert!(answer.is_none(), "part {}: expected no answer, got {:?}", part, answer);
+}
+
+/// Checks the answer of a part against the recorded one, ignoring trailing whitespace like
+/// `cargo solve`. An example part without a recorded answer must not be solved yet, a real
+/// input part without one is skipped.
+#[track_caller]
+pub fn check_recorded<A: Display>(part: u8, answer: Option<A>, source: &InputSource, day: impl Into<DayId>) {
+    let day = day.into();
+    let answers = Answers::try_for_source(day, source)
+        .unwrap_or_else(|e| panic!("could not read the answers of day {}: {}", day, e));
+    let expected = answers.get(part);
+    if expected.is_none() && matches!(source, InputSource::Inputs) {
+        eprintln!("skipped: part {} has no recorded answer", part);
+        return;
+    }
+    let answer = answer.map(|answer| answer.to_string());
+    let matches = match (&answer, expected) {
+        (Some(answer), Some(expected)) => answers::matches(answer, expected),
+        (None, None) => true,
+        _ => false,
+    };
+    assert!(matches, "part {}: expected {:?}, got {:?}", part, expected, answer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::int_parser;
+    use nom::{character::complete::char, multi::many1, sequence::terminated};
+
+    fn lines(input: &str) -> IResult<&str, Vec<u32>> {
+        many1(terminated(int_parser, char('\n')))(input)
+    }
+
+    #[test]
+    fn test_check_answer() {
+        check_answer(1, Some("CMZ".to_owned()), Some("CMZ"));
+        check_answer(2, Some(13), Some(13));
+        check_unsolved(2, None::<u32>);
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1: expected Some(14), got Some(13)")]
+    fn test_check_wrong_answer() {
+        check_answer(1, Some(13), Some(14));
+    }
+
+    #[test]
+    fn test_check_recorded() {
+        check_recorded(1, Some("CMZ"), &InputSource::Examples, 5);
+        check_recorded(2, Some(36), &InputSource::NamedExample("larger".into()), 9);
+        // a real input without a recorded answer is not checked.
+        check_recorded(1, Some(13), &InputSource::Inputs, (2015, 25));
+    }
+
+    #[test]
+    #[should_panic(expected = "part 2: expected Some(\"MCD\"), got Some(\"CMZ\")")]
+    fn test_check_wrong_recorded_answer() {
+        check_recorded(2, Some("CMZ"), &InputSource::Examples, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "src/examples/09-larger.txt:1:1")]
+    fn test_load_reports_parse_errors() {
+        load(&InputSource::NamedExample("larger".into()), 9, lines);
+    }
+
+    #[test]
+    fn test_load_skips_missing_inputs() {
+        assert_eq!(load(&InputSource::Inputs, (2015, 25), lines), None);
+    }
+}